@@ -0,0 +1,234 @@
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, TokenStream, TokenTree};
+
+/// Rename the identifier of an item to the given naming case style
+///
+/// Supported items are `fn`, `struct`, `enum`, `union`, `trait`, `type`, `mod`, `const` and `static`.
+/// Supported styles are `snake` (`snake_case`), `screaming_snake` (`SCREAMING_SNAKE_CASE`),
+/// `camel` (`camelCase`) and `pascal` (`PascalCase`).
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt_derive::rename_case;
+/// #[rename_case(style = "snake")]
+/// fn BrewCoffee() -> u32 {
+///     42
+/// }
+///
+/// #[rename_case(style = "pascal")]
+/// struct brew_coffee;
+///
+/// #[rename_case(style = "SCREAMING_SNAKE_CASE")]
+/// const brewCoffee: u32 = 7;
+///
+/// assert_eq!(brew_coffee(), 42);
+/// let _ = BrewCoffee;
+/// assert_eq!(BREW_COFFEE, 7);
+/// ```
+///
+/// Qualifiers such as `const fn`, `unsafe extern "C" fn` and `static mut` are skipped:
+///
+/// ```
+/// # use just_fmt_derive::rename_case;
+/// #[rename_case(style = "snake")]
+/// const fn BrewCoffee() -> u32 {
+///     42
+/// }
+///
+/// #[rename_case(style = "camel")]
+/// unsafe extern "C" fn grind_beans() {}
+///
+/// #[rename_case(style = "screaming_snake")]
+/// static mut brewCount: u32 = 0;
+///
+/// assert_eq!(brew_coffee(), 42);
+/// unsafe {
+///     grindBeans();
+///     BREW_COUNT += 1;
+/// }
+/// ```
+///
+/// Renaming to a keyword is an error:
+///
+/// ```compile_fail
+/// # use just_fmt_derive::rename_case;
+/// #[rename_case(style = "snake")]
+/// fn Match() {}
+/// ```
+#[proc_macro_attribute]
+pub fn rename_case(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let style = match parse_style(attr) {
+        Ok(style) => style,
+        Err(message) => return compile_error(&message),
+    };
+
+    let mut tokens: Vec<TokenTree> = item.into_iter().collect();
+    let name_index = tokens
+        .iter()
+        .position(|token| is_ident_in(token, &ITEM_KEYWORDS))
+        .and_then(|keyword_index| {
+            // Skip qualifiers and the keyword they lead to (`const fn`, `static mut`, `extern "C"`)
+            let offset = tokens[keyword_index + 1..].iter().position(|token| {
+                !is_ident_in(token, &ITEM_KEYWORDS)
+                    && !is_ident_in(token, &QUALIFIERS)
+                    && !matches!(token, TokenTree::Literal(_))
+            })?;
+            let index = keyword_index + 1 + offset;
+            matches!(tokens[index], TokenTree::Ident(_)).then_some(index)
+        });
+
+    let Some(name_index) = name_index else {
+        return compile_error("`rename_case` can only be applied to named items");
+    };
+    let TokenTree::Ident(name) = &tokens[name_index] else {
+        unreachable!()
+    };
+
+    let renamed = style.convert(&name.to_string());
+    if renamed.is_empty() || renamed.starts_with(|c: char| c.is_ascii_digit()) {
+        return compile_error(&format!(
+            "`{}` cannot be renamed to a valid identifier",
+            name
+        ));
+    }
+    if KEYWORDS.contains(&renamed.as_str()) {
+        return compile_error(&format!(
+            "`{}` cannot be renamed to `{}`, which is a keyword",
+            name, renamed
+        ));
+    }
+    tokens[name_index] = TokenTree::Ident(Ident::new(&renamed, name.span()));
+
+    tokens.into_iter().collect()
+}
+
+/// Keywords introducing an item whose name follows directly
+const ITEM_KEYWORDS: [&str; 9] = [
+    "fn", "struct", "enum", "union", "trait", "type", "mod", "const", "static",
+];
+
+/// Keywords that may sit between the item keyword and the name
+const QUALIFIERS: [&str; 5] = ["mut", "unsafe", "safe", "async", "extern"];
+
+/// Strict and reserved keywords, which cannot be used as plain identifiers
+const KEYWORDS: [&str; 52] = [
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "gen", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut",
+    "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final", "macro",
+    "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
+
+/// Check whether a token is one of the given identifiers
+fn is_ident_in(token: &TokenTree, idents: &[&str]) -> bool {
+    matches!(token, TokenTree::Ident(ident) if idents.contains(&ident.to_string().as_str()))
+}
+
+/// Case styles that produce valid Rust identifiers
+enum Style {
+    Snake,
+    ScreamingSnake,
+    Camel,
+    Pascal,
+}
+
+impl Style {
+    fn convert(&self, input: &str) -> String {
+        let words = split_words(input);
+        match self {
+            Style::Snake => words.join("_"),
+            Style::ScreamingSnake => words.join("_").to_uppercase(),
+            Style::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 {
+                        word.clone()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+            Style::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+        }
+    }
+}
+
+/// Parse the `style = "..."` attribute arguments
+fn parse_style(attr: TokenStream) -> Result<Style, String> {
+    let tokens: Vec<TokenTree> = attr.into_iter().collect();
+    let value = match tokens.as_slice() {
+        [
+            TokenTree::Ident(key),
+            TokenTree::Punct(eq),
+            TokenTree::Literal(value),
+        ] if key.to_string() == "style" && eq.as_char() == '=' => value.to_string(),
+        _ => return Err("expected `style = \"...\"`".to_string()),
+    };
+
+    match value.trim_matches('"') {
+        "snake" | "snake_case" => Ok(Style::Snake),
+        "screaming_snake" | "SCREAMING_SNAKE_CASE" => Ok(Style::ScreamingSnake),
+        "camel" | "camelCase" => Ok(Style::Camel),
+        "pascal" | "PascalCase" => Ok(Style::Pascal),
+        other => Err(format!(
+            "unsupported style `{}`, expected one of `snake`, `screaming_snake`, `camel`, `pascal`",
+            other
+        )),
+    }
+}
+
+/// Split an identifier into lowercase words
+///
+/// This follows the rules of `just_fmt::fmt_case_style::CaseFormatter`:
+/// `_` separates words, and a lowercase letter followed by an uppercase letter is a word boundary.
+/// A proc-macro crate cannot depend on the crate re-exporting it, hence the separate copy.
+fn split_words(input: &str) -> Vec<String> {
+    let input = input.strip_prefix("r#").unwrap_or(input);
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut prev_lowercase = false;
+
+    for c in input.chars() {
+        if c == '_' {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            prev_lowercase = false;
+            continue;
+        }
+        if prev_lowercase && c.is_uppercase() && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        current.extend(c.to_lowercase());
+        prev_lowercase = c.is_lowercase();
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Build a `compile_error!("...");` item
+fn compile_error(message: &str) -> TokenStream {
+    [
+        TokenTree::Ident(Ident::new("compile_error", proc_macro::Span::call_site())),
+        TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+        TokenTree::Group(Group::new(
+            Delimiter::Parenthesis,
+            TokenTree::Literal(Literal::string(message)).into(),
+        )),
+        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+    ]
+    .into_iter()
+    .collect()
+}
@@ -1,382 +1,5640 @@
+use std::collections::HashMap;
+
+/// Formatters compare equal when they split into the same word list,
+/// regardless of the original input style and of the [`SplitConfig`] output options.
+#[derive(Clone, Debug)]
 pub struct CaseFormatter {
     content: Vec<String>,
-}
 
-impl From<String> for CaseFormatter {
-    fn from(value: String) -> Self {
-        Self {
-            content: str_split(value),
-        }
-    }
+    /// Number of underscores preserved before the first word, see [`SplitConfig`]
+    leading_underscores: usize,
+
+    /// Number of underscores preserved after the last word, see [`SplitConfig`]
+    trailing_underscores: usize,
+
+    /// Number of `@` preserved before the first word, see [`SplitConfig::strip_at_prefix`]
+    at_prefix: usize,
+
+    /// Separator between adjacent numeric words in separator-less styles, see [`SplitConfig`]
+    numeric_separator: Option<String>,
+
+    /// Whether words keep their casing as typed, see [`SplitConfig::preserve_inner_case`]
+    preserve_inner_case: bool,
 }
 
-impl From<&String> for CaseFormatter {
-    fn from(value: &String) -> Self {
-        Self {
-            content: str_split(value.clone()),
-        }
+impl PartialEq for CaseFormatter {
+    fn eq(&self, other: &Self) -> bool {
+        self.content == other.content
     }
 }
 
-impl From<&str> for CaseFormatter {
-    fn from(value: &str) -> Self {
-        Self {
-            content: str_split(value.to_string()),
-        }
+impl Eq for CaseFormatter {}
+
+impl std::hash::Hash for CaseFormatter {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.content.hash(state);
     }
 }
 
-/// Split the string into segments for conversion
-fn str_split(input: String) -> Vec<String> {
-    let mut result = String::new();
-    let mut prev_space = false;
+/// Options controlling how [`CaseFormatter::from_custom`] splits its input
+///
+/// By default digits never start or end a word: they stay glued to the letters around them,
+/// and no case boundary is detected next to them. So `utf8_string` splits into `utf8 string`,
+/// while `mp3player` and even `http2Request` stay single words. Set `split_after_digits`
+/// to also end a word where a letter follows a digit.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct SplitConfig {
+    /// Whether to keep leading and trailing underscore runs (`_private`, `__init__`, `type_`).
+    /// The exact number of underscores is re-attached around every converted output,
+    /// e.g. `_internalValue` becomes `_internal_value` in snake_case and `__init__` becomes
+    /// `__Init__` in PascalCase. Disabled by default, where `_` is only a separator.
+    pub preserve_underscores: bool,
 
-    for c in input.chars() {
-        match c {
-            'a'..='z' | 'A'..='Z' | '0'..='9' => {
-                result.push(c);
-                prev_space = false;
-            }
-            '_' | ',' | '.' | '-' | ' ' => {
-                if !prev_space {
-                    result.push(' ');
-                    prev_space = true;
-                }
-            }
-            _ => {}
-        }
-    }
+    /// Prefixes dropped before splitting, such as Hungarian notation (`m_`, `s_`, `I`).
+    /// The longest matching prefix wins and matching is case-sensitive. A prefix that does not
+    /// end with a separator only matches at a case boundary, so `I` strips `IUserService` but
+    /// not `Item`. Nothing is stripped if it would leave the input without words.
+    pub strip_prefixes: Vec<String>,
 
-    let mut processed = String::new();
-    let mut chars = result.chars().peekable();
+    /// Suffixes dropped before splitting (`_t`, `_impl`, `Impl`), following the same rules
+    /// as `strip_prefixes`: a suffix not starting with a separator only matches at a case
+    /// boundary, so `Impl` strips `ParserImpl` but not `Simpl`.
+    pub strip_suffixes: Vec<String>,
 
-    while let Some(c) = chars.next() {
-        processed.push(c);
+    /// Whether to join a lone single letter onto the following word (`e-mail` becomes `email`).
+    /// A letter is merged only when separators (`_ , . -` or whitespace) or the start of the input
+    /// delimit it on both sides and a separator also precedes the next word, so case boundaries
+    /// never merge: `aValue` still splits into `a value`. Consecutive lone letters accumulate
+    /// (`u-s-army` becomes `usarmy`), and a lone letter at the end of the input is left as is.
+    pub merge_single_letters: bool,
 
-        // Detect case boundaries:
-        // when the current character is lowercase and the next is uppercase (e.g., "bre[wC]offee")
-        // Treat as a word boundary in PascalCase or camelCase, insert a space
-        if let Some(&next) = chars.peek()
-            && c.is_lowercase()
-            && next.is_uppercase()
-        {
-            processed.push(' ');
-        }
-    }
+    /// Whether to keep apostrophes between letters inside a word (`o'brien`, `don't`)
+    /// instead of dropping them. A typographic apostrophe (`’`) is kept as `'`.
+    pub preserve_apostrophes: bool,
 
-    processed
-        .to_lowercase()
-        .split_whitespace()
-        .map(|s| s.to_string())
-        .collect()
+    /// Whether a hyphen between letters is kept inside a word (`smith-jones`)
+    /// instead of separating words. Meant for text such as person names, not kebab-case input.
+    pub preserve_hyphens: bool,
+
+    /// Whether to ignore lowercase-to-uppercase transitions as word boundaries,
+    /// so `McDonald` stays a single word. Only explicit separators split words.
+    pub ignore_case_boundaries: bool,
+
+    /// Whether `/` separates words like `_` does, so URL-style input such as `api/user/profile`
+    /// splits into `api user profile`. Disabled by default, where slashes are dropped without
+    /// splitting (`brew/coffee` becomes `brewcoffee`).
+    pub split_on_slash: bool,
+
+    /// Whether `:` separates words like `_` does, so namespace-qualified names such as
+    /// `std::io::Error` or `key:value` split into their parts. Disabled by default,
+    /// where colons are dropped without splitting.
+    pub split_on_colon: bool,
+
+    /// Whether a leading `@` run, as in decorators and annotations (`@Component`, `@my-decorator`),
+    /// is dropped like any other unsupported character. Enabled by default; when disabled the `@`
+    /// run is kept in front of every converted output, so `@Component` becomes `@component`
+    /// in snake_case and `@@double` keeps both signs. Other `@` characters are always dropped.
+    pub strip_at_prefix: bool,
+
+    /// Whether words keep the casing they were typed with, so camelCase and PascalCase
+    /// output only adjusts the first letter of each word: `macOS` becomes `macOS` and `MacOS`
+    /// rather than `macOs` and `MacOs`. Words are still split at case boundaries, so `iPhone`
+    /// is `i Phone` and `bRewCofFee` is `b Rew Cof Fee`. Styles with a delimiter and
+    /// all-lowercase or all-uppercase styles are unaffected.
+    pub preserve_inner_case: bool,
+
+    /// Separator inserted in camelCase, PascalCase and flat output between a word ending
+    /// with a digit and a following purely numeric word, so `2023_04_01` renders as
+    /// `2023_04_01` rather than the ambiguous `20230401`, and splits back into the same words.
+    /// `None` uses `_`; `Some("")` joins such words without a separator.
+    pub numeric_separator: Option<String>,
+
+    /// Whether a letter following a digit starts a new word, so `mp3player` splits into
+    /// `mp3 player` like `mp3_player` does. A leading digit run becomes its own word too
+    /// (`3dModel` -> `3 d model`).
+    pub split_after_digits: bool,
+
+    /// Whether digit runs act as separators and are dropped, for legacy identifiers that use
+    /// numbers as delimiters: `section1subsection2` splits into `section subsection`.
+    /// Takes precedence over `split_after_digits`, as no digit is left to split after.
+    pub digits_as_separators: bool,
+
+    /// Abbreviations replaced after splitting (`cfg` -> `config`), matched per word ignoring case.
+    /// A replacement containing separators or case boundaries is split into several words
+    /// (`pk` -> `primary key`); the resulting words are never replaced again.
+    pub expand_abbreviations: Vec<(String, String)>,
+
+    /// Words shortened after splitting (`config` -> `cfg`), the inverse of
+    /// `expand_abbreviations` and following the same rules. Expansion is applied first.
+    pub abbreviate_words: Vec<(String, String)>,
 }
 
-impl CaseFormatter {
-    /// Convert to camelCase format (brewCoffee)
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// # use just_fmt::fmt_case_style::CaseFormatter;
-    /// let processor = CaseFormatter::from("brew_coffee");
-    /// assert_eq!(processor.to_camel_case(), "brewCoffee");
-    /// ```
-    pub fn to_camel_case(&self) -> String {
-        let mut result = String::new();
-        for (i, word) in self.content.iter().enumerate() {
-            if i == 0 {
-                result.push_str(&word.to_lowercase());
-            } else {
-                let mut chars = word.chars();
-                if let Some(first) = chars.next() {
-                    result.push_str(&first.to_uppercase().collect::<String>());
-                    result.push_str(&chars.collect::<String>().to_lowercase());
-                }
-            }
+impl Default for SplitConfig {
+    fn default() -> Self {
+        Self {
+            preserve_underscores: false,
+            strip_prefixes: Vec::new(),
+            strip_suffixes: Vec::new(),
+            merge_single_letters: false,
+            preserve_apostrophes: false,
+            preserve_hyphens: false,
+            ignore_case_boundaries: false,
+            split_on_slash: false,
+            split_on_colon: false,
+            strip_at_prefix: true,
+            preserve_inner_case: false,
+            numeric_separator: None,
+            split_after_digits: false,
+            digits_as_separators: false,
+            expand_abbreviations: Vec::new(),
+            abbreviate_words: Vec::new(),
         }
-        result
     }
+}
 
-    /// Convert to PascalCase format (BrewCoffee)
+impl SplitConfig {
+    /// Set the prefixes dropped before splitting, see [`SplitConfig::strip_prefixes`]
     ///
     /// # Examples
     ///
     /// ```
-    /// # use just_fmt::fmt_case_style::CaseFormatter;
-    /// let processor = CaseFormatter::from("brew_coffee");
-    /// assert_eq!(processor.to_pascal_case(), "BrewCoffee");
+    /// # use just_fmt::fmt_case_style::{CaseFormatter, SplitConfig};
+    /// let config = SplitConfig::default().strip_prefixes(&["m_", "s_", "I"]);
+    /// assert_eq!(CaseFormatter::from_custom("m_userName", &config).to_snake_case(), "user_name");
+    /// assert_eq!(CaseFormatter::from_custom("IUserService", &config).to_snake_case(), "user_service");
     /// ```
-    pub fn to_pascal_case(&self) -> String {
-        let mut result = String::new();
-        for word in &self.content {
-            let mut chars = word.chars();
-            if let Some(first) = chars.next() {
-                result.push_str(&first.to_uppercase().collect::<String>());
-                result.push_str(&chars.collect::<String>().to_lowercase());
-            }
-        }
-        result
+    pub fn strip_prefixes(mut self, prefixes: &[&str]) -> Self {
+        self.strip_prefixes = prefixes.iter().map(|p| p.to_string()).collect();
+        self
     }
 
-    /// Convert to kebab-case format (brew-coffee)
+    /// Set the suffixes dropped before splitting, see [`SplitConfig::strip_suffixes`]
     ///
     /// # Examples
     ///
     /// ```
-    /// # use just_fmt::fmt_case_style::CaseFormatter;
-    /// let processor = CaseFormatter::from("brew_coffee");
-    /// assert_eq!(processor.to_kebab_case(), "brew-coffee");
+    /// # use just_fmt::fmt_case_style::{CaseFormatter, SplitConfig};
+    /// let config = SplitConfig::default().strip_suffixes(&["_t", "Impl"]);
+    /// assert_eq!(CaseFormatter::from_custom("size_t", &config).to_snake_case(), "size");
+    /// assert_eq!(CaseFormatter::from_custom("ParserImpl", &config).to_snake_case(), "parser");
     /// ```
-    pub fn to_kebab_case(&self) -> String {
-        self.content.join("-").to_lowercase()
+    pub fn strip_suffixes(mut self, suffixes: &[&str]) -> Self {
+        self.strip_suffixes = suffixes.iter().map(|s| s.to_string()).collect();
+        self
     }
 
-    /// Convert to snake_case format (brew_coffee)
+    /// Set the abbreviations expanded after splitting, see [`SplitConfig::expand_abbreviations`]
     ///
     /// # Examples
     ///
     /// ```
-    /// # use just_fmt::fmt_case_style::CaseFormatter;
-    /// let processor = CaseFormatter::from("brewCoffee");
-    /// assert_eq!(processor.to_snake_case(), "brew_coffee");
+    /// # use just_fmt::fmt_case_style::{CaseFormatter, SplitConfig};
+    /// let config = SplitConfig::default().expand_abbreviations(&[("cfg", "config"), ("num", "number")]);
+    /// assert_eq!(CaseFormatter::from_custom("cfgMaxNum", &config).to_snake_case(), "config_max_number");
     /// ```
-    pub fn to_snake_case(&self) -> String {
-        self.content.join("_").to_lowercase()
+    pub fn expand_abbreviations(mut self, abbreviations: &[(&str, &str)]) -> Self {
+        self.expand_abbreviations = abbreviations
+            .iter()
+            .map(|(short, long)| (short.to_string(), long.to_string()))
+            .collect();
+        self
     }
 
-    /// Convert to dot.case format (brew.coffee)
+    /// Set the words abbreviated after splitting, see [`SplitConfig::abbreviate_words`]
     ///
     /// # Examples
     ///
     /// ```
-    /// # use just_fmt::fmt_case_style::CaseFormatter;
-    /// let processor = CaseFormatter::from("brew_coffee");
-    /// assert_eq!(processor.to_dot_case(), "brew.coffee");
+    /// # use just_fmt::fmt_case_style::{CaseFormatter, SplitConfig};
+    /// let config = SplitConfig::default().abbreviate_words(&[("config", "cfg"), ("number", "num")]);
+    /// assert_eq!(CaseFormatter::from_custom("config_max_number", &config).to_camel_case(), "cfgMaxNum");
     /// ```
-    pub fn to_dot_case(&self) -> String {
-        self.content.join(".").to_lowercase()
+    pub fn abbreviate_words(mut self, words: &[(&str, &str)]) -> Self {
+        self.abbreviate_words = words
+            .iter()
+            .map(|(long, short)| (long.to_string(), short.to_string()))
+            .collect();
+        self
     }
 
-    /// Convert to Title Case format (Brew Coffee)
-    ///
-    /// # Examples
+    /// Splitting suited for person names, see [`CaseFormatter::to_name_case`]
     ///
-    /// ```
-    /// # use just_fmt::fmt_case_style::CaseFormatter;
-    /// let processor = CaseFormatter::from("brew_coffee");
-    /// assert_eq!(processor.to_title_case(), "Brew Coffee");
-    /// ```
-    pub fn to_title_case(&self) -> String {
-        let mut result = String::new();
-        for word in &self.content {
-            let mut chars = word.chars();
-            if let Some(first) = chars.next() {
-                result.push_str(&first.to_uppercase().collect::<String>());
-                result.push_str(&chars.collect::<String>().to_lowercase());
-            }
-            result.push(' ');
+    /// Only whitespace and `_ , .` separate words, while apostrophes, hyphens
+    /// and inner capitals (`McDonald`) stay part of the word.
+    pub fn person_name() -> Self {
+        Self {
+            preserve_apostrophes: true,
+            preserve_hyphens: true,
+            ignore_case_boundaries: true,
+            ..Default::default()
         }
-        result.pop();
-        result
     }
 
-    /// Convert to lower case format (brew coffee)
-    ///
-    /// # Examples
+    /// Splitting suited for namespace-qualified names, see [`CaseFormatter::from_namespace`]
     ///
-    /// ```
-    /// # use just_fmt::fmt_case_style::CaseFormatter;
-    /// let processor = CaseFormatter::from("BREW COFFEE");
-    /// assert_eq!(processor.to_lower_case(), "brew coffee");
-    /// ```
-    pub fn to_lower_case(&self) -> String {
-        self.content.join(" ").to_lowercase()
+    /// `:` (and so `::`) and `/` separate words in addition to the default separators.
+    pub fn namespace() -> Self {
+        Self {
+            split_on_slash: true,
+            split_on_colon: true,
+            ..Default::default()
+        }
     }
+}
 
-    /// Convert to UPPER CASE format (BREW COFFEE)
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// # use just_fmt::fmt_case_style::CaseFormatter;
-    /// let processor = CaseFormatter::from("brew coffee");
-    /// assert_eq!(processor.to_upper_case(), "BREW COFFEE");
-    /// ```
-    pub fn to_upper_case(&self) -> String {
-        self.content.join(" ").to_uppercase()
-    }
+/// Naming case styles supported by [`CaseFormatter`]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub enum Case {
+    /// camelCase (brewCoffee)
+    Camel,
+
+    /// PascalCase (BrewCoffee)
+    Pascal,
+
+    /// snake_case (brew_coffee)
+    Snake,
+
+    /// SCREAMING_SNAKE_CASE (BREW_COFFEE)
+    ScreamingSnake,
+
+    /// kebab-case (brew-coffee)
+    Kebab,
+
+    /// SCREAMING-KEBAB-CASE (BREW-COFFEE)
+    ScreamingKebab,
+
+    /// dot.case (brew.coffee)
+    Dot,
+
+    /// Title Case (Brew Coffee)
+    Title,
+
+    /// lower case (brew coffee)
+    Lower,
+
+    /// UPPER CASE (BREW COFFEE)
+    Upper,
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::fmt_case_style::CaseFormatter;
+/// Every [`Case`] variant, in declaration order
+#[cfg(feature = "arbitrary")]
+pub(crate) const ALL_CASES: [Case; 10] = [
+    Case::Camel,
+    Case::Pascal,
+    Case::Snake,
+    Case::ScreamingSnake,
+    Case::Kebab,
+    Case::ScreamingKebab,
+    Case::Dot,
+    Case::Title,
+    Case::Lower,
+    Case::Upper,
+];
 
-    #[test]
-    fn test_processer() {
-        let test_cases = vec![
-            ("brew_coffee", "brewCoffee"),
-            ("brew, coffee", "brewCoffee"),
-            ("brew-coffee", "brewCoffee"),
-            ("Brew.Coffee", "brewCoffee"),
-            ("bRewCofFee", "bRewCofFee"),
-            ("brewCoffee", "brewCoffee"),
-            ("b&rewCoffee", "brewCoffee"),
-            ("BrewCoffee", "brewCoffee"),
-            ("brew.coffee", "brewCoffee"),
-            ("Brew_Coffee", "brewCoffee"),
-            ("BREW COFFEE", "brewCoffee"),
-        ];
+/// Common SQL reserved words, quoted by [`CaseFormatter::to_sql_identifier`]
+const SQL_RESERVED_WORDS: [&str; 58] = [
+    "all",
+    "alter",
+    "and",
+    "any",
+    "as",
+    "asc",
+    "between",
+    "by",
+    "case",
+    "check",
+    "column",
+    "constraint",
+    "create",
+    "cross",
+    "current",
+    "default",
+    "delete",
+    "desc",
+    "distinct",
+    "drop",
+    "else",
+    "end",
+    "exists",
+    "false",
+    "foreign",
+    "from",
+    "full",
+    "grant",
+    "group",
+    "having",
+    "in",
+    "index",
+    "inner",
+    "insert",
+    "into",
+    "is",
+    "join",
+    "key",
+    "left",
+    "like",
+    "limit",
+    "not",
+    "null",
+    "on",
+    "or",
+    "order",
+    "primary",
+    "references",
+    "right",
+    "select",
+    "set",
+    "table",
+    "then",
+    "true",
+    "union",
+    "update",
+    "user",
+    "where",
+];
 
-        for (input, expected) in test_cases {
-            let processor = CaseFormatter::from(input);
-            assert_eq!(
-                processor.to_camel_case(),
-                expected,
-                "Failed for input: '{}'",
-                input
-            );
+/// Naming convention for JSON object keys, see [`CaseFormatter::to_json_key_with`]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash, Default)]
+pub enum JsonNamingConvention {
+    /// camelCase, common in JavaScript APIs (userName)
+    #[default]
+    Camel,
+
+    /// snake_case, common in Python and Ruby APIs (user_name)
+    Snake,
+
+    /// kebab-case, used by some REST APIs (user-name)
+    Kebab,
+
+    /// PascalCase, common in .NET APIs (UserName)
+    Pascal,
+}
+
+impl JsonNamingConvention {
+    /// The case style keys are converted to
+    pub fn case(self) -> Case {
+        match self {
+            JsonNamingConvention::Camel => Case::Camel,
+            JsonNamingConvention::Snake => Case::Snake,
+            JsonNamingConvention::Kebab => Case::Kebab,
+            JsonNamingConvention::Pascal => Case::Pascal,
         }
     }
+}
 
-    #[test]
-    fn test_conversions() {
-        let processor = CaseFormatter::from("brewCoffee");
+/// How a single word is cased by a [`CaseSpec`]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub enum WordCase {
+    /// All lowercase (brew)
+    Lower,
 
-        assert_eq!(processor.to_upper_case(), "BREW COFFEE");
-        assert_eq!(processor.to_lower_case(), "brew coffee");
-        assert_eq!(processor.to_title_case(), "Brew Coffee");
-        assert_eq!(processor.to_dot_case(), "brew.coffee");
-        assert_eq!(processor.to_snake_case(), "brew_coffee");
-        assert_eq!(processor.to_kebab_case(), "brew-coffee");
-        assert_eq!(processor.to_pascal_case(), "BrewCoffee");
-        assert_eq!(processor.to_camel_case(), "brewCoffee");
-    }
+    /// All uppercase (BREW)
+    Upper,
+
+    /// First character uppercase, the rest lowercase (Brew)
+    Capitalize,
 }
 
-/// Convert to camelCase format (brewCoffee)
-///
-/// # Examples
-///
-/// ```
-/// # use just_fmt::camel_case;
-/// assert_eq!(camel_case!("brew_coffee"), "brewCoffee");
-/// ```
-#[macro_export]
-macro_rules! camel_case {
-    ($input:expr) => {{
-        use just_fmt::fmt_case_style::CaseFormatter;
-        CaseFormatter::from($input).to_camel_case()
-    }};
+impl WordCase {
+    fn apply(self, word: &str) -> String {
+        match self {
+            WordCase::Lower => word.to_lowercase(),
+            WordCase::Upper => word.to_uppercase(),
+            WordCase::Capitalize => {
+                let mut chars = word.chars();
+                match chars.next() {
+                    Some(first) => first
+                        .to_uppercase()
+                        .chain(chars.as_str().to_lowercase().chars())
+                        .collect(),
+                    None => String::new(),
+                }
+            }
+        }
+    }
 }
 
-/// Convert to UPPER CASE format (BREW COFFEE)
+/// A case style described by its delimiter and word casing, see [`CaseFormatter::format`]
 ///
-/// # Examples
-///
-/// ```
-/// # use just_fmt::upper_case;
-/// assert_eq!(upper_case!("brew coffee"), "BREW COFFEE");
-/// ```
-#[macro_export]
-macro_rules! upper_case {
-    ($input:expr) => {{
-        use just_fmt::fmt_case_style::CaseFormatter;
-        CaseFormatter::from($input).to_upper_case()
-    }};
+/// camelCase is `("", Lower, Capitalize)`, PascalCase `("", Capitalize, Capitalize)`
+/// and snake_case `("_", Lower, Lower)`. Every [`Case`] converts into its spec.
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
+pub struct CaseSpec {
+    /// Inserted between two words
+    pub delimiter: String,
+
+    /// Casing of the first word
+    pub first_word: WordCase,
+
+    /// Casing of every word after the first
+    pub rest_words: WordCase,
 }
 
-/// Convert to lower case format (brew coffee)
-///
-/// # Examples
-///
-/// ```
-/// # use just_fmt::lower_case;
-/// assert_eq!(lower_case!("BREW COFFEE"), "brew coffee");
-/// ```
-#[macro_export]
-macro_rules! lower_case {
-    ($input:expr) => {{
-        use just_fmt::fmt_case_style::CaseFormatter;
-        CaseFormatter::from($input).to_lower_case()
-    }};
+impl CaseSpec {
+    /// Create a spec from its delimiter and word casing
+    pub fn new(delimiter: impl Into<String>, first_word: WordCase, rest_words: WordCase) -> Self {
+        Self {
+            delimiter: delimiter.into(),
+            first_word,
+            rest_words,
+        }
+    }
 }
 
-/// Convert to Title Case format (Brew Coffee)
-///
-/// # Examples
-///
-/// ```
-/// # use just_fmt::title_case;
-/// assert_eq!(title_case!("brew_coffee"), "Brew Coffee");
-/// ```
-#[macro_export]
-macro_rules! title_case {
-    ($input:expr) => {{
-        use just_fmt::fmt_case_style::CaseFormatter;
-        CaseFormatter::from($input).to_title_case()
-    }};
+impl From<Case> for CaseSpec {
+    fn from(case: Case) -> Self {
+        match case {
+            Case::Camel => Self::new("", WordCase::Lower, WordCase::Capitalize),
+            Case::Pascal => Self::new("", WordCase::Capitalize, WordCase::Capitalize),
+            Case::Snake => Self::new("_", WordCase::Lower, WordCase::Lower),
+            Case::ScreamingSnake => Self::new("_", WordCase::Upper, WordCase::Upper),
+            Case::Kebab => Self::new("-", WordCase::Lower, WordCase::Lower),
+            Case::ScreamingKebab => Self::new("-", WordCase::Upper, WordCase::Upper),
+            Case::Dot => Self::new(".", WordCase::Lower, WordCase::Lower),
+            Case::Title => Self::new(" ", WordCase::Capitalize, WordCase::Capitalize),
+            Case::Lower => Self::new(" ", WordCase::Lower, WordCase::Lower),
+            Case::Upper => Self::new(" ", WordCase::Upper, WordCase::Upper),
+        }
+    }
 }
 
-/// Convert to dot.case format (brew.coffee)
-///
-/// # Examples
-///
-/// ```
-/// # use just_fmt::dot_case;
-/// assert_eq!(dot_case!("brew_coffee"), "brew.coffee");
-/// ```
-#[macro_export]
-macro_rules! dot_case {
-    ($input:expr) => {{
-        use just_fmt::fmt_case_style::CaseFormatter;
-        CaseFormatter::from($input).to_dot_case()
-    }};
+/// Which words [`CaseFormatter::into_string_with`] capitalizes
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash, Default)]
+pub enum CapitalizationMode {
+    /// Keep every word lowercase (brew/coffee)
+    #[default]
+    None,
+
+    /// Capitalize only the first word (Brew/coffee)
+    FirstWord,
+
+    /// Capitalize every word (Brew/Coffee)
+    AllWords,
 }
 
-/// Convert to snake_case format (brew_coffee)
-///
-/// # Examples
-///
-/// ```
-/// # use just_fmt::snake_case;
-/// assert_eq!(snake_case!("brewCoffee"), "brew_coffee");
-/// ```
-#[macro_export]
-macro_rules! snake_case {
-    ($input:expr) => {{
-        use just_fmt::fmt_case_style::CaseFormatter;
-        CaseFormatter::from($input).to_snake_case()
-    }};
+/// Error type for fallible [`CaseFormatter`] construction.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum CaseError {
+    /// The input contained a non-ASCII byte at the given offset.
+    NonAscii { position: usize, byte: u8 },
 }
 
-/// Convert to kebab-case format (brew-coffee)
+impl std::fmt::Display for CaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CaseError::NonAscii { position, byte } => {
+                write!(f, "Non-ASCII byte 0x{:02x} at offset {}", byte, position)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CaseError {}
+
+/// Error type for [`CaseFormatter::from_utf8`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Utf8CaseError {
+    /// The input contained an invalid UTF-8 sequence starting at the given offset.
+    InvalidSequence { position: usize },
+}
+
+impl std::fmt::Display for Utf8CaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Utf8CaseError::InvalidSequence { position } => {
+                write!(f, "Invalid UTF-8 sequence at offset {}", position)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Utf8CaseError {}
+
+/// Error type for [`CaseFormatter::try_from_strict`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct CaseParseError {
+    /// Byte offset of the rejected character
+    pub position: usize,
+    /// The character that would have been discarded
+    pub character: char,
+}
+
+impl std::fmt::Display for CaseParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Unsupported character {:?} at offset {}",
+            self.character, self.position
+        )
+    }
+}
+
+impl std::error::Error for CaseParseError {}
+
+/// Error type for constructing a [`StrictCaseFormatter`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum StrictCaseError {
+    /// The input split into zero words, e.g. it was empty or only separators.
+    NoWords,
+
+    /// The input contained a character that would have been discarded.
+    UnsupportedCharacter(CaseParseError),
+}
+
+impl std::fmt::Display for StrictCaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StrictCaseError::NoWords => write!(f, "Input contains no words"),
+            StrictCaseError::UnsupportedCharacter(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for StrictCaseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StrictCaseError::NoWords => None,
+            StrictCaseError::UnsupportedCharacter(e) => Some(e),
+        }
+    }
+}
+
+impl From<CaseParseError> for StrictCaseError {
+    fn from(e: CaseParseError) -> Self {
+        StrictCaseError::UnsupportedCharacter(e)
+    }
+}
+
+/// Error type for [`CaseFormatter::format_template`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TemplateError {
+    /// The template used a placeholder name that is not supported.
+    UnknownPlaceholder(String),
+
+    /// A `{` at the given offset was never closed.
+    UnclosedPlaceholder { position: usize },
+
+    /// A lone `}` at the given offset was not part of a placeholder or `}}` escape.
+    UnmatchedBrace { position: usize },
+}
+
+impl std::fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateError::UnknownPlaceholder(name) => {
+                write!(f, "Unknown template placeholder '{{{}}}'", name)
+            }
+            TemplateError::UnclosedPlaceholder { position } => {
+                write!(f, "Unclosed placeholder starting at offset {}", position)
+            }
+            TemplateError::UnmatchedBrace { position } => {
+                write!(f, "Unmatched '}}' at offset {}", position)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+/// Stop word lists for [`CaseFormatter::to_title_case_with_stop_words`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct StopWordList {
+    words: &'static [&'static str],
+}
+
+impl StopWordList {
+    /// Chicago Manual of Style: articles, coordinating conjunctions and all prepositions
+    pub fn chicago() -> Self {
+        Self {
+            words: &[
+                "a",
+                "an",
+                "the",
+                "and",
+                "but",
+                "for",
+                "nor",
+                "or",
+                "so",
+                "yet",
+                "as",
+                "about",
+                "above",
+                "across",
+                "after",
+                "against",
+                "along",
+                "among",
+                "around",
+                "at",
+                "before",
+                "behind",
+                "below",
+                "beneath",
+                "beside",
+                "between",
+                "beyond",
+                "by",
+                "down",
+                "during",
+                "except",
+                "from",
+                "in",
+                "inside",
+                "into",
+                "like",
+                "near",
+                "of",
+                "off",
+                "on",
+                "onto",
+                "out",
+                "outside",
+                "over",
+                "past",
+                "since",
+                "through",
+                "throughout",
+                "to",
+                "toward",
+                "under",
+                "underneath",
+                "until",
+                "up",
+                "upon",
+                "via",
+                "with",
+                "within",
+                "without",
+            ],
+        }
+    }
+
+    /// APA style: articles, conjunctions and prepositions of three letters or fewer
+    pub fn apa() -> Self {
+        Self {
+            words: &[
+                "a", "an", "the", "and", "as", "but", "for", "if", "nor", "or", "so", "yet", "at",
+                "by", "in", "of", "off", "on", "per", "to", "up", "via",
+            ],
+        }
+    }
+
+    /// Get the stop words
+    pub fn words(&self) -> &'static [&'static str] {
+        self.words
+    }
+}
+
+impl From<String> for CaseFormatter {
+    fn from(value: String) -> Self {
+        Self::with_content(str_split(value))
+    }
+}
+
+impl From<&String> for CaseFormatter {
+    fn from(value: &String) -> Self {
+        Self::with_content(str_split(value.clone()))
+    }
+}
+
+impl From<&str> for CaseFormatter {
+    fn from(value: &str) -> Self {
+        Self::with_content(str_split(value.to_string()))
+    }
+}
+
+/// Case conversions called directly on strings
+///
+/// Each method splits the string with [`CaseFormatter::from`] and converts the words,
+/// which suits one-off conversions; build a [`CaseFormatter`] once to convert the same
+/// input to several styles or to split it with a custom [`SplitConfig`].
 ///
 /// # Examples
 ///
 /// ```
-/// # use just_fmt::kebab_case;
-/// assert_eq!(kebab_case!("brew_coffee"), "brew-coffee");
+/// use just_fmt::fmt_case_style::CaseConvert;
+/// assert_eq!("brew_coffee".to_camel_case(), "brewCoffee");
+/// assert_eq!(String::from("brewCoffee").to_kebab_case(), "brew-coffee");
 /// ```
-#[macro_export]
-macro_rules! kebab_case {
-    ($input:expr) => {{
-        use just_fmt::fmt_case_style::CaseFormatter;
-        CaseFormatter::from($input).to_kebab_case()
-    }};
+pub trait CaseConvert {
+    /// Split the string into a [`CaseFormatter`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use just_fmt::fmt_case_style::CaseConvert;
+    /// assert_eq!("brewCoffee".to_case_formatter().words(), ["brew", "coffee"]);
+    /// ```
+    fn to_case_formatter(&self) -> CaseFormatter;
+
+    /// Convert to camelCase format, see [`CaseFormatter::to_camel_case`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use just_fmt::fmt_case_style::CaseConvert;
+    /// assert_eq!("brew_coffee".to_camel_case(), "brewCoffee");
+    /// ```
+    fn to_camel_case(&self) -> String {
+        self.to_case_formatter().to_camel_case()
+    }
+
+    /// Convert to PascalCase format, see [`CaseFormatter::to_pascal_case`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use just_fmt::fmt_case_style::CaseConvert;
+    /// assert_eq!("brew_coffee".to_pascal_case(), "BrewCoffee");
+    /// ```
+    fn to_pascal_case(&self) -> String {
+        self.to_case_formatter().to_pascal_case()
+    }
+
+    /// Convert to snake_case format, see [`CaseFormatter::to_snake_case`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use just_fmt::fmt_case_style::CaseConvert;
+    /// assert_eq!("brew_coffee".to_snake_case(), "brew_coffee");
+    /// ```
+    fn to_snake_case(&self) -> String {
+        self.to_case_formatter().to_snake_case()
+    }
+
+    /// Convert to SCREAMING_SNAKE_CASE format, see [`CaseFormatter::to_screaming_snake_case`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use just_fmt::fmt_case_style::CaseConvert;
+    /// assert_eq!("brew_coffee".to_screaming_snake_case(), "BREW_COFFEE");
+    /// ```
+    fn to_screaming_snake_case(&self) -> String {
+        self.to_case_formatter().to_screaming_snake_case()
+    }
+
+    /// Convert to kebab-case format, see [`CaseFormatter::to_kebab_case`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use just_fmt::fmt_case_style::CaseConvert;
+    /// assert_eq!("brew_coffee".to_kebab_case(), "brew-coffee");
+    /// ```
+    fn to_kebab_case(&self) -> String {
+        self.to_case_formatter().to_kebab_case()
+    }
+
+    /// Convert to SCREAMING-KEBAB-CASE format, see [`CaseFormatter::to_screaming_kebab_case`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use just_fmt::fmt_case_style::CaseConvert;
+    /// assert_eq!("brew_coffee".to_screaming_kebab_case(), "BREW-COFFEE");
+    /// ```
+    fn to_screaming_kebab_case(&self) -> String {
+        self.to_case_formatter().to_screaming_kebab_case()
+    }
+
+    /// Convert to dot.case format, see [`CaseFormatter::to_dot_case`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use just_fmt::fmt_case_style::CaseConvert;
+    /// assert_eq!("brew_coffee".to_dot_case(), "brew.coffee");
+    /// ```
+    fn to_dot_case(&self) -> String {
+        self.to_case_formatter().to_dot_case()
+    }
+
+    /// Convert to path/case format, see [`CaseFormatter::to_path_case`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use just_fmt::fmt_case_style::CaseConvert;
+    /// assert_eq!("brew_coffee".to_path_case(), "brew/coffee");
+    /// ```
+    fn to_path_case(&self) -> String {
+        self.to_case_formatter().to_path_case()
+    }
+
+    /// Convert to Title Case format, see [`CaseFormatter::to_title_case`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use just_fmt::fmt_case_style::CaseConvert;
+    /// assert_eq!("brew_coffee".to_title_case(), "Brew Coffee");
+    /// ```
+    fn to_title_case(&self) -> String {
+        self.to_case_formatter().to_title_case()
+    }
+
+    /// Convert to lower case format, see [`CaseFormatter::to_lower_case`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use just_fmt::fmt_case_style::CaseConvert;
+    /// assert_eq!("brew_coffee".to_lower_case(), "brew coffee");
+    /// ```
+    fn to_lower_case(&self) -> String {
+        self.to_case_formatter().to_lower_case()
+    }
+
+    /// Convert to UPPER CASE format, see [`CaseFormatter::to_upper_case`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use just_fmt::fmt_case_style::CaseConvert;
+    /// assert_eq!("brew_coffee".to_upper_case(), "BREW COFFEE");
+    /// ```
+    fn to_upper_case(&self) -> String {
+        self.to_case_formatter().to_upper_case()
+    }
+
+    /// Convert to a URL slug format, see [`CaseFormatter::to_slug`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use just_fmt::fmt_case_style::CaseConvert;
+    /// assert_eq!("brew_coffee".to_slug(), "brew-coffee");
+    /// ```
+    fn to_slug(&self) -> String {
+        self.to_case_formatter().to_slug()
+    }
+
+    /// Convert to the given case style, see [`CaseFormatter::convert`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use just_fmt::fmt_case_style::{Case, CaseConvert};
+    /// assert_eq!("brew_coffee".to_case(Case::Pascal), "BrewCoffee");
+    /// ```
+    fn to_case(&self, case: Case) -> String {
+        self.to_case_formatter().convert(case)
+    }
 }
 
-/// Convert to PascalCase format (BrewCoffee)
+impl CaseConvert for &str {
+    fn to_case_formatter(&self) -> CaseFormatter {
+        CaseFormatter::from(*self)
+    }
+}
+
+impl CaseConvert for String {
+    fn to_case_formatter(&self) -> CaseFormatter {
+        CaseFormatter::from(self)
+    }
+}
+
+impl CaseConvert for &String {
+    fn to_case_formatter(&self) -> CaseFormatter {
+        CaseFormatter::from(*self)
+    }
+}
+
+impl std::ops::Index<usize> for CaseFormatter {
+    type Output = str;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.content[index]
+    }
+}
+
+impl std::ops::Add<&CaseFormatter> for CaseFormatter {
+    type Output = CaseFormatter;
+
+    fn add(self, rhs: &CaseFormatter) -> Self::Output {
+        self.merge(rhs)
+    }
+}
+
+impl std::ops::AddAssign<&CaseFormatter> for CaseFormatter {
+    fn add_assign(&mut self, rhs: &CaseFormatter) {
+        *self = self.merge(rhs);
+    }
+}
+
+/// A [`CaseFormatter`] guaranteed to hold at least one word, built from lossless input
+///
+/// Construction goes through `TryFrom`, which fails where the lenient `From` conversions of
+/// [`CaseFormatter`] would silently drop characters or produce an empty identifier.
+/// It dereferences to [`CaseFormatter`] for all conversions.
 ///
 /// # Examples
 ///
 /// ```
-/// # use just_fmt::pascal_case;
-/// assert_eq!(pascal_case!("brew_coffee"), "BrewCoffee");
+/// # use just_fmt::fmt_case_style::{StrictCaseError, StrictCaseFormatter};
+/// let processor = StrictCaseFormatter::try_from("brewCoffee").unwrap();
+/// assert_eq!(processor.to_snake_case(), "brew_coffee");
+///
+/// assert_eq!(StrictCaseFormatter::try_from("__").err(), Some(StrictCaseError::NoWords));
+/// assert!(matches!(
+///     StrictCaseFormatter::try_from("brew;coffee"),
+///     Err(StrictCaseError::UnsupportedCharacter(_))
+/// ));
 /// ```
-#[macro_export]
-macro_rules! pascal_case {
-    ($input:expr) => {{
-        use just_fmt::fmt_case_style::CaseFormatter;
-        CaseFormatter::from($input).to_pascal_case()
-    }};
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StrictCaseFormatter(CaseFormatter);
+
+impl StrictCaseFormatter {
+    /// Unwrap the inner [`CaseFormatter`]
+    pub fn into_inner(self) -> CaseFormatter {
+        self.0
+    }
+}
+
+impl std::ops::Deref for StrictCaseFormatter {
+    type Target = CaseFormatter;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<StrictCaseFormatter> for CaseFormatter {
+    fn from(value: StrictCaseFormatter) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<&str> for StrictCaseFormatter {
+    type Error = StrictCaseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let formatter = CaseFormatter::try_from_strict(value)?;
+        if formatter.is_empty() {
+            return Err(StrictCaseError::NoWords);
+        }
+        Ok(Self(formatter))
+    }
+}
+
+impl TryFrom<&String> for StrictCaseFormatter {
+    type Error = StrictCaseError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<String> for StrictCaseFormatter {
+    type Error = StrictCaseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+/// Split ASCII bytes into segments for conversion
+///
+/// Produces the same words as [`str_split`] without going through `char`s.
+/// The caller guarantees `input` is ASCII.
+fn ascii_split(input: &[u8]) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut prev: Option<u8> = None;
+
+    for &b in input {
+        match b {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' => {
+                if prev.is_some_and(|p| p.is_ascii_lowercase()) && b.is_ascii_uppercase() {
+                    words.push(std::mem::take(&mut current));
+                }
+                current.push(b.to_ascii_lowercase() as char);
+                prev = Some(b);
+            }
+            b if is_separator(b as char) => {
+                if !current.is_empty() {
+                    words.push(std::mem::take(&mut current));
+                }
+                prev = None;
+            }
+            _ => {}
+        }
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+/// Change the case of the first character of a word only, keeping the rest as typed
+fn with_first_char_case(word: &str, upper: bool) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) if upper => first.to_uppercase().chain(chars).collect(),
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Check whether the character separates words: `_ , . -` or any Unicode whitespace
+fn is_separator(c: char) -> bool {
+    matches!(c, '_' | ',' | '.' | '-') || c.is_whitespace()
+}
+
+/// Split the string into segments for conversion
+///
+/// Digits never start a new word: a run like `html5`, `v2` or `sha256` stays a single word,
+/// so case conversion only affects its leading letter.
+fn str_split(input: String) -> Vec<String> {
+    str_split_custom(&input, &SplitConfig::default())
+}
+
+/// Split the string into lowercase segments, honoring the punctuation and boundary options of `config`
+///
+/// With [`SplitConfig::preserve_inner_case`] the segments keep the casing as typed.
+fn str_split_custom(input: &str, config: &SplitConfig) -> Vec<String> {
+    let mut words = str_split_preserving_case(input, config);
+    if config.preserve_inner_case {
+        return words;
+    }
+    for word in &mut words {
+        if word.chars().any(char::is_uppercase) {
+            *word = word.to_lowercase();
+        }
+    }
+    words
+}
+
+/// Split the string into segments like [`str_split_custom`], keeping the casing as typed
+///
+/// Case boundaries are still detected, so `bRewCofFee` splits into `b Rew Cof Fee`.
+fn str_split_preserving_case(input: &str, config: &SplitConfig) -> Vec<String> {
+    let mut result = String::new();
+    let mut pending_boundary = false;
+    let mut pending_inner = None;
+
+    // Any run of separators, of whatever kind, becomes a single word boundary.
+    // The boundary is only emitted before the next word, so leading and trailing runs vanish.
+    // Preserved inner punctuation is only emitted between two alphanumerics of the same word.
+    for c in input.chars() {
+        match c {
+            '0'..='9' if config.digits_as_separators => {
+                pending_boundary = !result.is_empty();
+                pending_inner = None;
+            }
+            'a'..='z' | 'A'..='Z' | '0'..='9' => {
+                if pending_boundary {
+                    result.push(' ');
+                    pending_boundary = false;
+                }
+                if let Some(inner) = pending_inner.take() {
+                    result.push(inner);
+                }
+                result.push(c);
+            }
+            '\'' | '’' if config.preserve_apostrophes => {
+                pending_inner = (!result.is_empty() && !pending_boundary).then_some('\'');
+            }
+            '-' if config.preserve_hyphens => {
+                pending_inner = (!result.is_empty() && !pending_boundary).then_some('-');
+            }
+            c if is_separator(c)
+                || (config.split_on_slash && c == '/')
+                || (config.split_on_colon && c == ':') =>
+            {
+                pending_boundary = !result.is_empty();
+                pending_inner = None;
+            }
+            _ => {}
+        }
+    }
+
+    let mut processed = String::new();
+    let mut chars = result.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        processed.push(c);
+
+        // Detect case boundaries:
+        // when the current character is lowercase and the next is uppercase (e.g., "bre[wC]offee")
+        // Treat as a word boundary in PascalCase or camelCase, insert a space
+        if !config.ignore_case_boundaries
+            && let Some(&next) = chars.peek()
+            && c.is_lowercase()
+            && next.is_uppercase()
+        {
+            processed.push(' ');
+        }
+
+        // Optionally end a word after its digits (e.g., "mp3[p]layer")
+        if config.split_after_digits
+            && let Some(&next) = chars.peek()
+            && c.is_ascii_digit()
+            && next.is_ascii_alphabetic()
+        {
+            processed.push(' ');
+        }
+    }
+
+    processed
+        .split_whitespace()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+impl CaseFormatter {
+    /// Create a formatter from a word list, without preserved underscores
+    fn with_content(content: Vec<String>) -> Self {
+        Self {
+            content,
+            leading_underscores: 0,
+            trailing_underscores: 0,
+            at_prefix: 0,
+            preserve_inner_case: false,
+            numeric_separator: None,
+        }
+    }
+
+    /// Create a formatter using `SplitConfig` to control how the input is split
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::{CaseFormatter, SplitConfig};
+    /// let config = SplitConfig {
+    ///     preserve_underscores: true,
+    ///     ..Default::default()
+    /// };
+    /// let processor = CaseFormatter::from_custom("_internalValue", &config);
+    /// assert_eq!(processor.to_snake_case(), "_internal_value");
+    /// assert_eq!(CaseFormatter::from_custom("__init__", &config).to_pascal_case(), "__Init__");
+    /// ```
+    pub fn from_custom(input: impl Into<String>, config: &SplitConfig) -> Self {
+        let input = input.into();
+        let input = strip_affixes(&input, &config.strip_prefixes, &config.strip_suffixes);
+        let words = if config.merge_single_letters {
+            merge_single_letters(input, config)
+        } else {
+            str_split_custom(input, config)
+        };
+        let words = replace_words(words, &config.expand_abbreviations);
+        let words = replace_words(words, &config.abbreviate_words);
+        let mut formatter = Self::with_content(words);
+
+        formatter.numeric_separator = config.numeric_separator.clone();
+        formatter.preserve_inner_case = config.preserve_inner_case;
+
+        let mut input = input;
+        if !config.strip_at_prefix {
+            let without_at = input.trim_start_matches('@');
+            formatter.at_prefix = input.len() - without_at.len();
+            input = without_at;
+        }
+
+        if config.preserve_underscores {
+            let without_leading = input.trim_start_matches('_');
+            formatter.leading_underscores = input.len() - without_leading.len();
+            formatter.trailing_underscores =
+                without_leading.len() - without_leading.trim_end_matches('_').len();
+        }
+
+        formatter
+    }
+
+    /// Create a formatter directly from a word list
+    ///
+    /// The words are used as-is, without being split again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from_words(vec!["brew".to_string(), "coffee".to_string()]);
+    /// assert_eq!(processor.to_camel_case(), "brewCoffee");
+    /// ```
+    pub fn from_words(words: Vec<String>) -> Self {
+        Self::with_content(words)
+    }
+
+    /// Create a formatter from a namespace-qualified name, see [`SplitConfig::namespace`]
+    ///
+    /// `::`, `:`, `/` and `.` separate words in addition to the default separators,
+    /// covering inputs such as `::cpp::namespace::Type`, `java.style.ClassName`,
+    /// `key:value` and ROS topic names like `/robot/arm:joint`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from_namespace("::cpp::namespace::Type");
+    /// assert_eq!(processor.to_snake_case(), "cpp_namespace_type");
+    /// assert_eq!(CaseFormatter::from_namespace("/robot/arm:joint").to_camel_case(), "robotArmJoint");
+    /// ```
+    pub fn from_namespace(input: impl Into<String>) -> Self {
+        Self::from_custom(input, &SplitConfig::namespace())
+    }
+
+    /// Get the split word list
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("brewCoffee");
+    /// assert_eq!(processor.words(), ["brew", "coffee"]);
+    /// ```
+    pub fn words(&self) -> &[String] {
+        &self.content
+    }
+
+    /// Get the word at `index`, or `None` if it is out of bounds
+    ///
+    /// Indexing with `processor[index]` returns the word directly and panics instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("brewCoffee");
+    /// assert_eq!(processor.word_at(1), Some("coffee"));
+    /// assert_eq!(processor.word_at(2), None);
+    /// assert_eq!(&processor[0], "brew");
+    /// ```
+    pub fn word_at(&self, index: usize) -> Option<&str> {
+        self.content.get(index).map(String::as_str)
+    }
+
+    /// Consume the formatter and take ownership of the split word list
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("brewCoffee");
+    /// assert_eq!(processor.into_words(), vec!["brew", "coffee"]);
+    /// ```
+    pub fn into_words(self) -> Vec<String> {
+        self.content
+    }
+
+    /// Check whether the input produced no words at all
+    ///
+    /// Symbol-only and whitespace-only inputs produce no words,
+    /// and every converter returns an empty string for them.
+    /// Numeric-only inputs are kept as a single word.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// assert!(CaseFormatter::from("!!!").is_empty());
+    /// assert!(!CaseFormatter::from("123").is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.content.is_empty()
+    }
+
+    /// Append a word to the end of the word list as-is
+    ///
+    /// The word is not split, call [`CaseFormatter::normalize`] afterwards
+    /// if it may contain separators or mixed case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let mut processor = CaseFormatter::from("brew");
+    /// processor.push_word("coffee");
+    /// assert_eq!(processor.to_snake_case(), "brew_coffee");
+    /// ```
+    pub fn push_word(&mut self, word: impl Into<String>) {
+        self.content.push(word.into());
+    }
+
+    /// Create a new formatter with the split words of `word` inserted at the front
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("coffee");
+    /// assert_eq!(processor.with_prefix("brew").to_camel_case(), "brewCoffee");
+    /// assert_eq!(processor.with_prefix("hotBrew").to_snake_case(), "hot_brew_coffee");
+    /// ```
+    pub fn with_prefix(&self, word: &str) -> CaseFormatter {
+        let mut prefixed = self.clone();
+        prefixed.content = str_split(word.to_string());
+        prefixed.content.extend(self.content.iter().cloned());
+        prefixed
+    }
+
+    /// Create a new formatter with the split words of `word` appended at the back
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("coffee_parser");
+    /// assert_eq!(processor.with_suffix("Impl").to_pascal_case(), "CoffeeParserImpl");
+    /// ```
+    pub fn with_suffix(&self, word: &str) -> CaseFormatter {
+        let mut suffixed = self.clone();
+        suffixed.content.extend(str_split(word.to_string()));
+        suffixed
+    }
+
+    /// Create a new formatter with the words of `other` appended after the words of `self`
+    ///
+    /// Leading underscores and the numeric separator come from `self`,
+    /// trailing underscores from `other`. The `+` and `+=` operators do the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let prefix = CaseFormatter::from("brew");
+    /// let suffix = CaseFormatter::from("CoffeeMachine");
+    /// assert_eq!(prefix.merge(&suffix).to_snake_case(), "brew_coffee_machine");
+    /// assert_eq!((prefix + &suffix).to_camel_case(), "brewCoffeeMachine");
+    /// ```
+    pub fn merge(&self, other: &CaseFormatter) -> CaseFormatter {
+        let mut merged = self.clone();
+        merged.content.extend(other.content.iter().cloned());
+        merged.trailing_underscores = other.trailing_underscores;
+        merged
+    }
+
+    /// Create a new formatter with the word order reversed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("com.example.app");
+    /// assert_eq!(processor.reversed().to_dot_case(), "app.example.com");
+    /// ```
+    pub fn reversed(&self) -> CaseFormatter {
+        let mut reversed = self.clone();
+        reversed.content.reverse();
+        reversed
+    }
+
+    /// Create a new formatter with the words sorted alphabetically
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("readOnlyHidden");
+    /// assert_eq!(processor.sort_words().to_snake_case(), "hidden_only_read");
+    /// ```
+    pub fn sort_words(&self) -> CaseFormatter {
+        self.sort_words_in(WordOrder::Lexicographic)
+    }
+
+    /// Create a new formatter with the words sorted in the given order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::{CaseFormatter, WordOrder};
+    /// let processor = CaseFormatter::from("v10_v9_v2");
+    /// assert_eq!(processor.sort_words_in(WordOrder::Natural).to_snake_case(), "v2_v9_v10");
+    /// assert_eq!(processor.sort_words_in(WordOrder::Lexicographic).to_snake_case(), "v10_v2_v9");
+    /// ```
+    pub fn sort_words_in(&self, order: WordOrder) -> CaseFormatter {
+        self.sort_words_by(|a, b| order.compare(a, b))
+    }
+
+    /// Create a new formatter with the words sorted by the given comparator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("readOnlyHidden");
+    /// let by_length = processor.sort_words_by(|a, b| a.len().cmp(&b.len()));
+    /// assert_eq!(by_length.to_snake_case(), "read_only_hidden");
+    /// ```
+    pub fn sort_words_by<F>(&self, mut compare: F) -> CaseFormatter
+    where
+        F: FnMut(&str, &str) -> std::cmp::Ordering,
+    {
+        let mut sorted = self.clone();
+        sorted.content.sort_by(|a, b| compare(a, b));
+        sorted
+    }
+
+    /// Re-split the current word list so every word is a single lowercase word
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let mut processor = CaseFormatter::from("brew");
+    /// processor.push_word("hotCoffee");
+    /// processor.normalize();
+    /// assert_eq!(processor.words(), ["brew", "hot", "coffee"]);
+    /// ```
+    pub fn normalize(&mut self) {
+        self.content = str_split(self.content.join(" "));
+    }
+
+    /// Check whether the first word equals `word`, ignoring case
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("getUserName");
+    /// assert!(processor.starts_with_word("GET"));
+    /// assert!(!processor.starts_with_word("ge"));
+    /// ```
+    pub fn starts_with_word(&self, word: &str) -> bool {
+        self.content.first().is_some_and(|w| word_eq(w, word))
+    }
+
+    /// Check whether the last word equals `word`, ignoring case
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("user_profile_service");
+    /// assert!(processor.ends_with_word("Service"));
+    /// assert!(!processor.ends_with_word("vice"));
+    /// ```
+    pub fn ends_with_word(&self, word: &str) -> bool {
+        self.content.last().is_some_and(|w| word_eq(w, word))
+    }
+
+    /// Check whether any word equals `word`, ignoring case
+    ///
+    /// Only whole words match, never substrings of a word.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("userProfileService");
+    /// assert!(processor.contains_word("profile"));
+    /// assert!(!processor.contains_word("file"));
+    /// ```
+    pub fn contains_word(&self, word: &str) -> bool {
+        self.content.iter().any(|w| word_eq(w, word))
+    }
+
+    /// Check whether the word at `index` equals `word`, ignoring case
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("userProfileService");
+    /// assert!(processor.contains_word_at(1, "Profile"));
+    /// assert!(!processor.contains_word_at(0, "profile"));
+    /// assert!(!processor.contains_word_at(3, "profile"));
+    /// ```
+    pub fn contains_word_at(&self, index: usize, word: &str) -> bool {
+        self.content.get(index).is_some_and(|w| word_eq(w, word))
+    }
+
+    /// Separator needed between two adjacent words so numeric groups stay distinct
+    fn numeric_separator_between(&self, prev: &str, word: &str) -> &str {
+        let numeric = !word.is_empty() && word.chars().all(|c| c.is_ascii_digit());
+        if numeric && prev.ends_with(|c: char| c.is_ascii_digit()) {
+            self.numeric_separator.as_deref().unwrap_or("_")
+        } else {
+            ""
+        }
+    }
+
+    /// Re-attach the preserved `@` prefix and leading and trailing underscores to a converted output
+    fn attach_underscores(&self, converted: String) -> String {
+        if self.leading_underscores == 0 && self.trailing_underscores == 0 && self.at_prefix == 0 {
+            return converted;
+        }
+        format!(
+            "{}{}{}{}",
+            "@".repeat(self.at_prefix),
+            "_".repeat(self.leading_underscores),
+            converted,
+            "_".repeat(self.trailing_underscores)
+        )
+    }
+
+    /// Create a formatter from ASCII bytes, splitting them without UTF-8 decoding
+    ///
+    /// The result is the same as building the formatter from the equivalent `&str`,
+    /// but avoids the `char`-based path. Fails on the first non-ASCII byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::{CaseError, CaseFormatter};
+    /// let processor = CaseFormatter::from_ascii_bytes(b"brewCoffee").unwrap();
+    /// assert_eq!(processor.to_snake_case(), "brew_coffee");
+    ///
+    /// assert_eq!(
+    ///     CaseFormatter::from_ascii_bytes("café".as_bytes()).err(),
+    ///     Some(CaseError::NonAscii { position: 3, byte: 0xc3 })
+    /// );
+    /// ```
+    pub fn from_ascii_bytes(bytes: &[u8]) -> Result<Self, CaseError> {
+        if let Some(position) = bytes.iter().position(|b| !b.is_ascii()) {
+            return Err(CaseError::NonAscii {
+                position,
+                byte: bytes[position],
+            });
+        }
+        Ok(Self::with_content(ascii_split(bytes)))
+    }
+
+    /// Create a formatter, rejecting input that would lose characters
+    ///
+    /// Every character must be an ASCII letter, an ASCII digit or a separator;
+    /// anything the lenient [`From`] conversions silently drop is reported instead.
+    /// A `TryFrom<&str>` impl is not possible next to the existing `From<&str>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::{CaseFormatter, CaseParseError};
+    /// let processor = CaseFormatter::try_from_strict("user_name").unwrap();
+    /// assert_eq!(processor.to_camel_case(), "userName");
+    ///
+    /// assert_eq!(
+    ///     CaseFormatter::try_from_strict("user;DROP TABLE").err(),
+    ///     Some(CaseParseError { position: 4, character: ';' })
+    /// );
+    /// ```
+    pub fn try_from_strict(input: &str) -> Result<Self, CaseParseError> {
+        if let Some((position, character)) = input
+            .char_indices()
+            .find(|&(_, c)| !c.is_ascii_alphanumeric() && !is_separator(c))
+        {
+            return Err(CaseParseError {
+                position,
+                character,
+            });
+        }
+        Ok(Self::from(input))
+    }
+
+    /// Create a formatter from a comma-separated list of words
+    ///
+    /// Whitespace around each token is trimmed and empty tokens are skipped.
+    /// Each token is split like any other input, so `userName` still yields two words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from_csv("Brew , Coffee , Machine");
+    /// assert_eq!(processor.words(), ["brew", "coffee", "machine"]);
+    /// ```
+    pub fn from_csv(csv_line: &str) -> Self {
+        Self::with_content(
+            csv_line
+                .split(',')
+                .map(str::trim)
+                .filter(|token| !token.is_empty())
+                .flat_map(|token| str_split(token.to_string()))
+                .collect(),
+        )
+    }
+
+    /// Create a formatter from UTF-8 encoded bytes
+    ///
+    /// Fails with the offset of the first invalid UTF-8 sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::{CaseFormatter, Utf8CaseError};
+    /// let processor = CaseFormatter::from_utf8("brewCafé".as_bytes()).unwrap();
+    /// assert_eq!(processor.to_snake_case(), "brew_caf");
+    ///
+    /// assert_eq!(
+    ///     CaseFormatter::from_utf8(b"brew\xffcoffee").err(),
+    ///     Some(Utf8CaseError::InvalidSequence { position: 4 })
+    /// );
+    /// ```
+    pub fn from_utf8(bytes: &[u8]) -> Result<Self, Utf8CaseError> {
+        match std::str::from_utf8(bytes) {
+            Ok(input) => Ok(Self::from(input)),
+            Err(err) => Err(Utf8CaseError::InvalidSequence {
+                position: err.valid_up_to(),
+            }),
+        }
+    }
+
+    /// Create a formatter from possibly invalid UTF-8 bytes
+    ///
+    /// Invalid sequences are dropped like any other unsupported character,
+    /// so they never introduce a word boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from_utf8_lossy(b"brew_cof\xfffee");
+    /// assert_eq!(processor.to_snake_case(), "brew_coffee");
+    /// ```
+    pub fn from_utf8_lossy(bytes: &[u8]) -> Self {
+        Self::from(String::from_utf8_lossy(bytes).into_owned())
+    }
+
+    /// Create a formatter from an environment variable name, stripping the given prefix first
+    ///
+    /// The prefix is matched in its SCREAMING_SNAKE_CASE form followed by `_`,
+    /// the same way [`CaseFormatter::to_env_var`] joins it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from_env_var("MYAPP_DATABASE_URL", Some("myapp"));
+    /// assert_eq!(processor.to_dot_case(), "database.url");
+    /// ```
+    pub fn from_env_var(name: &str, prefix: Option<&str>) -> Self {
+        let name = match prefix.map(env_var_prefix) {
+            Some(prefix) if !prefix.is_empty() => name
+                .strip_prefix(prefix.as_str())
+                .and_then(|rest| rest.strip_prefix('_'))
+                .unwrap_or(name),
+            _ => name,
+        };
+        CaseFormatter::from(name)
+    }
+
+    /// Convert to camelCase format (brewCoffee)
+    ///
+    /// When the input starts with digits there is no leading letter to lowercase,
+    /// so the leading digit run acts as the first word and the letters after it are capitalized
+    /// (`123abc` -> `123Abc`, `2dGame` -> `2DGame`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("brew_coffee");
+    /// assert_eq!(processor.to_camel_case(), "brewCoffee");
+    /// ```
+    pub fn to_camel_case(&self) -> String {
+        self.format(&CaseSpec::from(Case::Camel))
+    }
+
+    /// Convert to PascalCase format (BrewCoffee)
+    ///
+    /// Like [`CaseFormatter::to_camel_case`], letters after a leading digit run are capitalized
+    /// (`123abc` -> `123Abc`). Digits inside a word are kept as they are and only the first
+    /// letter of each word is capitalized, so `utf8_string` becomes `Utf8String` and `mp3player`
+    /// becomes `Mp3player`; see [`SplitConfig`] for how digits split words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("brew_coffee");
+    /// assert_eq!(processor.to_pascal_case(), "BrewCoffee");
+    /// ```
+    pub fn to_pascal_case(&self) -> String {
+        self.format(&CaseSpec::from(Case::Pascal))
+    }
+
+    /// Convert using a custom [`CaseSpec`], the building block of every fixed style
+    ///
+    /// Words are cased by `first_word` and `rest_words` and joined by `delimiter`.
+    /// Without a delimiter, adjacent numeric words are kept apart by the numeric separator
+    /// (see [`SplitConfig`]). When the other words are [`WordCase::Capitalize`]d, as in camelCase
+    /// and PascalCase, a first word starting with digits also gets the letter after them
+    /// capitalized (`123abc` -> `123Abc`) unless `first_word` is [`WordCase::Upper`].
+    /// Preserved underscores are attached around the result. A formatter split with
+    /// [`SplitConfig::preserve_inner_case`] only recases the first letter of each word
+    /// when there is no delimiter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::{CaseFormatter, CaseSpec, WordCase};
+    /// let processor = CaseFormatter::from("brew_coffee_machine");
+    /// let train = CaseSpec::new("-", WordCase::Capitalize, WordCase::Capitalize);
+    /// assert_eq!(processor.format(&train), "Brew-Coffee-Machine");
+    ///
+    /// let shouty_camel = CaseSpec::new("", WordCase::Upper, WordCase::Capitalize);
+    /// assert_eq!(processor.format(&shouty_camel), "BREWCoffeeMachine");
+    /// ```
+    pub fn format(&self, spec: &CaseSpec) -> String {
+        // Keeping the inner casing only makes sense when words are told apart by their first letter
+        let apply = |case: WordCase, word: &str| {
+            if self.preserve_inner_case && spec.delimiter.is_empty() && case != WordCase::Upper {
+                with_first_char_case(word, case == WordCase::Capitalize)
+            } else {
+                case.apply(word)
+            }
+        };
+        let mut result = String::new();
+        for (i, word) in self.content.iter().enumerate() {
+            if i == 0 {
+                if spec.delimiter.is_empty()
+                    && spec.first_word != WordCase::Upper
+                    && spec.rest_words == WordCase::Capitalize
+                    && word.starts_with(|c: char| c.is_ascii_digit())
+                {
+                    result.push_str(&capitalize_after_leading_digits(word));
+                } else {
+                    result.push_str(&apply(spec.first_word, word));
+                }
+                continue;
+            }
+
+            if spec.delimiter.is_empty() {
+                result.push_str(self.numeric_separator_between(&self.content[i - 1], word));
+            } else {
+                result.push_str(&spec.delimiter);
+            }
+            result.push_str(&apply(spec.rest_words, word));
+        }
+        self.attach_underscores(result)
+    }
+
+    /// Convert to PascalCase format, keeping a word that follows a digit lowercase (Http2request)
+    ///
+    /// Digits stay attached to the word before them, and the word after a digit
+    /// is glued on without capitalization: `http2_request` becomes `Http2request`
+    /// instead of `Http2Request`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("http2_request");
+    /// assert_eq!(processor.to_pascal_case_with_numbers_attached(), "Http2request");
+    /// assert_eq!(processor.to_pascal_case(), "Http2Request");
+    /// ```
+    pub fn to_pascal_case_with_numbers_attached(&self) -> String {
+        let mut result = String::new();
+        for (i, word) in self.content.iter().enumerate() {
+            if i == 0 && word.starts_with(|c: char| c.is_ascii_digit()) {
+                result.push_str(&capitalize_after_leading_digits(word));
+            } else if result.ends_with(|c: char| c.is_ascii_digit()) {
+                result.push_str(self.numeric_separator_between(&self.content[i - 1], word));
+                result.push_str(&word.to_lowercase());
+            } else {
+                let mut chars = word.chars();
+                if let Some(first) = chars.next() {
+                    result.push_str(&first.to_uppercase().collect::<String>());
+                    result.push_str(&chars.collect::<String>().to_lowercase());
+                }
+            }
+        }
+        self.attach_underscores(result)
+    }
+
+    /// Convert to kebab-case format (brew-coffee)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("brew_coffee");
+    /// assert_eq!(processor.to_kebab_case(), "brew-coffee");
+    /// ```
+    pub fn to_kebab_case(&self) -> String {
+        self.format(&CaseSpec::from(Case::Kebab))
+    }
+
+    /// Convert to SCREAMING-KEBAB-CASE format (BREW-COFFEE)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("brew_coffee");
+    /// assert_eq!(processor.to_kebab_case_upper(), "BREW-COFFEE");
+    /// ```
+    pub fn to_kebab_case_upper(&self) -> String {
+        self.format(&CaseSpec::from(Case::ScreamingKebab))
+    }
+
+    /// Convert to SCREAMING-KEBAB-CASE format (BREW-COFFEE)
+    ///
+    /// Alias of [`CaseFormatter::to_kebab_case_upper`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("brewCoffee");
+    /// assert_eq!(processor.to_screaming_kebab_case(), "BREW-COFFEE");
+    /// ```
+    pub fn to_screaming_kebab_case(&self) -> String {
+        self.to_kebab_case_upper()
+    }
+
+    /// Convert to snake_case format (brew_coffee)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("brewCoffee");
+    /// assert_eq!(processor.to_snake_case(), "brew_coffee");
+    /// ```
+    pub fn to_snake_case(&self) -> String {
+        self.format(&CaseSpec::from(Case::Snake))
+    }
+
+    /// Convert to SCREAMING_SNAKE_CASE format (BREW_COFFEE)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("brewCoffee");
+    /// assert_eq!(processor.to_screaming_snake_case(), "BREW_COFFEE");
+    /// ```
+    pub fn to_screaming_snake_case(&self) -> String {
+        self.format(&CaseSpec::from(Case::ScreamingSnake))
+    }
+
+    /// Convert to an environment variable name (MYAPP_BREW_COFFEE)
+    ///
+    /// The words and the optional prefix are rendered in SCREAMING_SNAKE_CASE and joined by `_`.
+    /// The prefix goes through the same splitting as any input, so characters outside `[A-Z0-9_]`
+    /// are dropped or turned into `_`. Since POSIX names must not start with a digit,
+    /// such a name is prefixed with `_`.
+    ///
+    /// There is no separate zero-argument form: `to_env_var(None)` is the unprefixed name,
+    /// which only differs from [`CaseFormatter::to_screaming_snake_case`] by that leading `_`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("database.pool.maxSize");
+    /// assert_eq!(processor.to_env_var(Some("myapp")), "MYAPP_DATABASE_POOL_MAX_SIZE");
+    /// assert_eq!(processor.to_env_var(None), "DATABASE_POOL_MAX_SIZE");
+    /// ```
+    pub fn to_env_var(&self, prefix: Option<&str>) -> String {
+        let mut result = prefix.map(env_var_prefix).unwrap_or_default();
+        let name = self.to_screaming_snake_case();
+        if !result.is_empty() && !name.is_empty() {
+            result.push('_');
+        }
+        result.push_str(&name);
+
+        if result.starts_with(|c: char| c.is_ascii_digit()) {
+            result.insert(0, '_');
+        }
+        result
+    }
+
+    /// Convert to dot.case format (brew.coffee)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("brew_coffee");
+    /// assert_eq!(processor.to_dot_case(), "brew.coffee");
+    /// ```
+    pub fn to_dot_case(&self) -> String {
+        self.format(&CaseSpec::from(Case::Dot))
+    }
+
+    /// Convert to path/case format (brew/coffee)
+    ///
+    /// `/` is not a word separator by default, so slashes already in the input are dropped
+    /// without splitting (`brew/coffee` becomes the single word `brewcoffee`);
+    /// enable [`SplitConfig::split_on_slash`] to split such input on `/` as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("brew.coffee.now");
+    /// assert_eq!(processor.to_path_case(), "brew/coffee/now");
+    /// ```
+    pub fn to_path_case(&self) -> String {
+        self.format(&CaseSpec::new("/", WordCase::Lower, WordCase::Lower))
+    }
+
+    /// Convert to Title Case format (Brew Coffee)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("brew_coffee");
+    /// assert_eq!(processor.to_title_case(), "Brew Coffee");
+    /// ```
+    pub fn to_title_case(&self) -> String {
+        self.format(&CaseSpec::from(Case::Title))
+    }
+
+    /// Convert to Title Case format, keeping stop words lowercase (The Art of Brewing)
+    ///
+    /// Stop words are compared ignoring case. The first and last words are
+    /// always capitalized. See [`StopWordList`] for common style guide lists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::{CaseFormatter, StopWordList};
+    /// let processor = CaseFormatter::from("the_art_of_brewing");
+    /// assert_eq!(processor.to_title_case_with_stop_words(&["the", "of"]), "The Art of Brewing");
+    /// assert_eq!(
+    ///     processor.to_title_case_with_stop_words(StopWordList::chicago().words()),
+    ///     "The Art of Brewing"
+    /// );
+    /// ```
+    pub fn to_title_case_with_stop_words(&self, stop_words: &[&str]) -> String {
+        self.to_title_case_custom(&TitleCaseOptions {
+            stop_words: stop_words.iter().map(|w| w.to_string()).collect(),
+            preserve_all_caps_tokens: false,
+            ..Default::default()
+        })
+    }
+
+    /// Convert to person-name casing (McDonald O'Brien van der Berg)
+    ///
+    /// Works best on a formatter split with [`SplitConfig::person_name`],
+    /// which keeps apostrophes, hyphens and inner capitals inside words.
+    /// See [`NameCaseOptions`] for the rules applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::{CaseFormatter, SplitConfig};
+    /// let processor =
+    ///     CaseFormatter::from_custom("MCDONALD o'brien van der berg", &SplitConfig::person_name());
+    /// assert_eq!(processor.to_name_case(), "McDonald O'Brien van der Berg");
+    /// ```
+    pub fn to_name_case(&self) -> String {
+        self.to_name_case_custom(&NameCaseOptions::default())
+    }
+
+    /// Convert to person-name casing with custom options
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::{CaseFormatter, NameCaseOptions, SplitConfig};
+    /// let options = NameCaseOptions {
+    ///     mc_mac_prefixes: false,
+    ///     ..Default::default()
+    /// };
+    /// let processor = CaseFormatter::from_custom("mack smith-jones", &SplitConfig::person_name());
+    /// assert_eq!(processor.to_name_case_custom(&options), "Mack Smith-Jones");
+    /// ```
+    pub fn to_name_case_custom(&self, options: &NameCaseOptions) -> String {
+        let names: Vec<String> = self
+            .content
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                if i != 0
+                    && options
+                        .particles
+                        .iter()
+                        .any(|particle| word_eq(word, particle))
+                {
+                    word.to_lowercase()
+                } else {
+                    word.split('-')
+                        .map(|part| options.name_part_case(part))
+                        .collect::<Vec<_>>()
+                        .join("-")
+                }
+            })
+            .collect();
+        self.attach_underscores(names.join(" "))
+    }
+
+    /// Convert to Title Case format with custom options (World War II)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::{CaseFormatter, TitleCaseOptions};
+    /// let options = TitleCaseOptions {
+    ///     acronyms: vec!["nasa".to_string()],
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(CaseFormatter::from("world_war_ii").to_title_case_custom(&options), "World War II");
+    /// assert_eq!(CaseFormatter::from("nasa_launch").to_title_case_custom(&options), "NASA Launch");
+    /// ```
+    pub fn to_title_case_custom(&self, options: &TitleCaseOptions) -> String {
+        let last = self.content.len().saturating_sub(1);
+        let mut result = String::new();
+        for (i, word) in self.content.iter().enumerate() {
+            if options.preserve_all_caps_tokens && options.is_all_caps_token(word) {
+                result.push_str(&word.to_uppercase());
+            } else if i != 0
+                && i != last
+                && options.stop_words.iter().any(|stop| word_eq(word, stop))
+            {
+                result.push_str(&word.to_lowercase());
+            } else {
+                let mut chars = word.chars();
+                if let Some(first) = chars.next() {
+                    result.push_str(&first.to_uppercase().collect::<String>());
+                    result.push_str(&chars.collect::<String>().to_lowercase());
+                }
+            }
+            result.push(' ');
+        }
+        result.pop();
+        self.attach_underscores(result)
+    }
+
+    /// Convert to lower case format (brew coffee)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("BREW COFFEE");
+    /// assert_eq!(processor.to_lower_case(), "brew coffee");
+    /// ```
+    pub fn to_lower_case(&self) -> String {
+        self.format(&CaseSpec::from(Case::Lower))
+    }
+
+    /// Convert to UPPER CASE format (BREW COFFEE)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("brew coffee");
+    /// assert_eq!(processor.to_upper_case(), "BREW COFFEE");
+    /// ```
+    pub fn to_upper_case(&self) -> String {
+        self.format(&CaseSpec::from(Case::Upper))
+    }
+
+    /// Convert to a URL slug (brew-coffee)
+    ///
+    /// Words are lowercased, stripped of anything outside `[a-z0-9]` and joined by `-`.
+    /// To transliterate accents or limit the length, use [`slugify`] on the original text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("Brew — the Coffee! (2024)");
+    /// assert_eq!(processor.to_slug(), "brew-the-coffee-2024");
+    /// ```
+    pub fn to_slug(&self) -> String {
+        join_slug(&self.content, None)
+    }
+
+    /// Convert to a filesystem-safe filename (brew_coffee.txt)
+    ///
+    /// The words are joined in snake_case, then control characters, `/`, `\\`, `:` and
+    /// characters disallowed on Windows are removed, along with trailing dots and spaces.
+    /// Reserved Windows basenames (`CON`, `NUL`, `COM1`, ...) get a `_` appended,
+    /// and a formatter without words yields the stem `_`.
+    ///
+    /// The extension, if any, is appended after a `.` with the same characters removed.
+    /// The whole name is capped at 255 bytes, shortening the stem first,
+    /// without splitting a UTF-8 code point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("Q3 Report: \"Final\" *draft*?");
+    /// assert_eq!(processor.to_filename(Some("pdf")), "q3_report_final_draft.pdf");
+    /// assert_eq!(CaseFormatter::from("con").to_filename(None), "con_");
+    /// ```
+    pub fn to_filename(&self, extension: Option<&str>) -> String {
+        const MAX_FILENAME_BYTES: usize = 255;
+
+        let is_allowed = |c: &char| {
+            !c.is_control()
+                && !matches!(c, '/' | '\\' | ':')
+                && !crate::fmt_path::UNFRIENDLY_CHARS.contains(c)
+        };
+
+        let extension: String = extension
+            .unwrap_or_default()
+            .chars()
+            .filter(is_allowed)
+            .collect();
+        let extension = extension
+            .trim_start_matches('.')
+            .trim_end_matches(['.', ' ']);
+        // Keep at least one byte of stem, then fit the extension into the rest
+        let extension = truncate_at_char_boundary(extension, MAX_FILENAME_BYTES - 2);
+        let stem_budget = if extension.is_empty() {
+            MAX_FILENAME_BYTES
+        } else {
+            MAX_FILENAME_BYTES - 1 - extension.len()
+        };
+
+        // Words may come from `from_words` or `push_word`, so the stem is filtered as well
+        let mut stem: String = self.to_snake_case().chars().filter(is_allowed).collect();
+        if stem.len() > stem_budget {
+            let cut = truncate_at_char_boundary(&stem, stem_budget);
+            // A cut stem does not end on `_` right before the extension dot
+            stem = if extension.is_empty() {
+                cut.to_string()
+            } else {
+                cut.trim_end_matches('_').to_string()
+            };
+        }
+        stem.truncate(stem.trim_end_matches(['.', ' ']).len());
+
+        // Checked after truncation, which may have cut the stem down to a reserved name
+        if !stem.is_empty() && crate::fmt_path::is_reserved_basename(&stem) {
+            if stem.len() < stem_budget {
+                stem.insert(stem.find('.').unwrap_or(stem.len()), '_');
+            } else {
+                stem.clear();
+            }
+        }
+        if stem.is_empty() {
+            stem.push('_');
+        }
+
+        if extension.is_empty() {
+            stem
+        } else {
+            format!("{}.{}", stem, extension)
+        }
+    }
+
+    /// Convert to the given case style, keeping the result within `max_len` bytes
+    ///
+    /// Results that fit are returned unchanged. Longer results are cut and end with
+    /// a 6 hex digit hash of the full result, joined with the style's separator,
+    /// so distinct long names stay distinct after truncation. The same input always
+    /// yields the same output. If `max_len` cannot even hold the hash, the hash is cut.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::{Case, CaseFormatter};
+    /// let processor = CaseFormatter::from("brew coffee with extra milk");
+    /// assert_eq!(processor.to_case_truncated_unique(Case::Kebab, 63), "brew-coffee-with-extra-milk");
+    ///
+    /// let truncated = processor.to_case_truncated_unique(Case::Kebab, 18);
+    /// assert_eq!(truncated.len(), 18);
+    /// assert!(truncated.starts_with("brew-coffee-"));
+    /// ```
+    pub fn to_case_truncated_unique(&self, case: Case, max_len: usize) -> String {
+        const HASH_LEN: usize = 6;
+
+        let full = self.convert(case);
+        if full.len() <= max_len {
+            return full;
+        }
+
+        let mut hash = format!("{:06x}", fnv1a(full.as_bytes()) & 0xff_ffff);
+        if matches!(
+            case,
+            Case::ScreamingSnake | Case::ScreamingKebab | Case::Upper
+        ) {
+            hash.make_ascii_uppercase();
+        }
+        let separator = match case {
+            Case::Camel | Case::Pascal => "",
+            Case::Snake | Case::ScreamingSnake => "_",
+            Case::Kebab | Case::ScreamingKebab => "-",
+            Case::Dot => ".",
+            Case::Title | Case::Lower | Case::Upper => " ",
+        };
+
+        if max_len < HASH_LEN + separator.len() + 1 {
+            hash.truncate(max_len);
+            return hash;
+        }
+
+        let prefix = truncate_at_char_boundary(&full, max_len - HASH_LEN - separator.len());
+        let prefix = prefix.trim_end_matches(['_', '-', '.', ' ']);
+        format!("{}{}{}", prefix, separator, hash)
+    }
+
+    /// Convert to an accessor name by prepending `prefix` as leading word(s)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::{Case, CaseFormatter};
+    /// let processor = CaseFormatter::from("user_name");
+    /// assert_eq!(processor.to_accessor("with", Case::Camel), "withUserName");
+    /// assert_eq!(processor.to_accessor("is", Case::Snake), "is_user_name");
+    /// ```
+    pub fn to_accessor(&self, prefix: &str, style: Case) -> String {
+        self.with_prefix(prefix).convert(style)
+    }
+
+    /// Convert to a camelCase getter name (getUserName)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("user_name");
+    /// assert_eq!(processor.to_getter(), "getUserName");
+    /// ```
+    pub fn to_getter(&self) -> String {
+        self.to_accessor("get", Case::Camel)
+    }
+
+    /// Convert to a camelCase setter name (setUserName)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("user_name");
+    /// assert_eq!(processor.to_setter(), "setUserName");
+    /// ```
+    pub fn to_setter(&self) -> String {
+        self.to_accessor("set", Case::Camel)
+    }
+
+    /// Convert to an unquoted SQL identifier (order_items), optionally quoting it when needed
+    ///
+    /// The identifier is snake_case, matching how PostgreSQL folds unquoted names.
+    /// With `quote_reserved`, names that are common SQL reserved words (`order`, `user`, ...)
+    /// or that start with a digit are wrapped in double quotes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// assert_eq!(CaseFormatter::from("OrderItems").to_sql_identifier(true), "order_items");
+    /// assert_eq!(CaseFormatter::from("Order").to_sql_identifier(true), "\"order\"");
+    /// assert_eq!(CaseFormatter::from("Order").to_sql_identifier(false), "order");
+    /// ```
+    pub fn to_sql_identifier(&self, quote_reserved: bool) -> String {
+        let identifier = self.to_snake_case();
+        let needs_quotes = SQL_RESERVED_WORDS.contains(&identifier.as_str())
+            || identifier.starts_with(|c: char| c.is_ascii_digit());
+        if quote_reserved && needs_quotes {
+            format!("\"{}\"", identifier)
+        } else {
+            identifier
+        }
+    }
+
+    /// Convert to a JSON object key using the JavaScript convention (userName)
+    ///
+    /// Same as [`CaseFormatter::to_camel_case`]. APIs following another ecosystem's convention
+    /// can pick it with [`CaseFormatter::to_json_key_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("user_name");
+    /// assert_eq!(processor.to_json_key(), "userName");
+    /// ```
+    pub fn to_json_key(&self) -> String {
+        self.to_json_key_with(JsonNamingConvention::default())
+    }
+
+    /// Convert to a JSON object key using the given naming convention
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::{CaseFormatter, JsonNamingConvention};
+    /// let processor = CaseFormatter::from("userName");
+    /// assert_eq!(processor.to_json_key_with(JsonNamingConvention::Snake), "user_name");
+    /// assert_eq!(processor.to_json_key_with(JsonNamingConvention::Kebab), "user-name");
+    /// ```
+    pub fn to_json_key_with(&self, convention: JsonNamingConvention) -> String {
+        self.convert(convention.case())
+    }
+
+    /// Convert to the given case style
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::{Case, CaseFormatter};
+    /// let processor = CaseFormatter::from("brew_coffee");
+    /// assert_eq!(processor.convert(Case::Pascal), "BrewCoffee");
+    /// ```
+    pub fn convert(&self, case: Case) -> String {
+        match case {
+            Case::Camel => self.to_camel_case(),
+            Case::Pascal => self.to_pascal_case(),
+            Case::Snake => self.to_snake_case(),
+            Case::ScreamingSnake => self.to_screaming_snake_case(),
+            Case::Kebab => self.to_kebab_case(),
+            Case::ScreamingKebab => self.to_kebab_case_upper(),
+            Case::Dot => self.to_dot_case(),
+            Case::Title => self.to_title_case(),
+            Case::Lower => self.to_lower_case(),
+            Case::Upper => self.to_upper_case(),
+        }
+    }
+
+    /// Convert to several case styles at once, keyed by style
+    ///
+    /// The input is split only once, however many styles are requested.
+    /// Duplicate styles produce a single entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::{Case, CaseFormatter};
+    /// let processor = CaseFormatter::from("brew_coffee");
+    /// let converted = processor.convert_many(&[Case::Camel, Case::Kebab]);
+    /// assert_eq!(converted[&Case::Camel], "brewCoffee");
+    /// assert_eq!(converted[&Case::Kebab], "brew-coffee");
+    /// ```
+    pub fn convert_many(&self, cases: &[Case]) -> HashMap<Case, String> {
+        cases
+            .iter()
+            .map(|&case| (case, self.convert(case)))
+            .collect()
+    }
+
+    /// Consume the formatter and join the words with `separator`, capitalized per `cap`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::{CapitalizationMode, CaseFormatter};
+    /// let processor = CaseFormatter::from("brew_coffee");
+    /// assert_eq!(processor.into_string_with("/", CapitalizationMode::AllWords), "Brew/Coffee");
+    /// ```
+    pub fn into_string_with(self, separator: &str, cap: CapitalizationMode) -> String {
+        self.convert_with(separator, |i, word| match cap {
+            CapitalizationMode::AllWords => capitalize(word),
+            CapitalizationMode::FirstWord if i == 0 => capitalize(word),
+            _ => word.to_lowercase(),
+        })
+    }
+
+    /// Convert with a custom per-word renderer, joining the rendered words with `sep`
+    ///
+    /// `f` receives the index and the stored word and returns the rendered word. Words are
+    /// lowercase, unless the formatter was split with [`SplitConfig::preserve_inner_case`]
+    /// (`OS` in `macOS`) or built from words that were not (see [`CaseFormatter::from_words`]).
+    /// This covers styles the crate does not ship; for words without digits,
+    /// [`CaseFormatter::to_camel_case`] is equivalent to
+    /// `convert_with("", |i, w| if i == 0 { lower(w) } else { capitalize(w) })`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("brew_hot_coffee_now");
+    /// let every_second = processor.convert_with(" ", |i, word| {
+    ///     if i % 2 == 1 {
+    ///         word.to_uppercase()
+    ///     } else {
+    ///         word.to_string()
+    ///     }
+    /// });
+    /// assert_eq!(every_second, "brew HOT coffee NOW");
+    /// ```
+    pub fn convert_with(&self, sep: &str, mut f: impl FnMut(usize, &str) -> String) -> String {
+        let rendered: Vec<String> = self
+            .content
+            .iter()
+            .enumerate()
+            .map(|(i, word)| f(i, word))
+            .collect();
+        self.attach_underscores(rendered.join(sep))
+    }
+
+    /// Render a template, replacing case placeholders with the converted name
+    ///
+    /// Supported placeholders are `{snake}`, `{camel}`, `{pascal}`, `{kebab}`,
+    /// `{screaming}`, `{title}`, `{dot}` and `{flat}`, matched case-insensitively.
+    /// Use `{{` and `}}` for literal braces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_case_style::CaseFormatter;
+    /// let processor = CaseFormatter::from("userProfile");
+    /// assert_eq!(processor.format_template("TBL_{SCREAMING}").unwrap(), "TBL_USER_PROFILE");
+    /// assert_eq!(processor.format_template("{pascal}Dto").unwrap(), "UserProfileDto");
+    /// assert_eq!(processor.format_template("{snake}.rs").unwrap(), "user_profile.rs");
+    /// ```
+    pub fn format_template(&self, template: &str) -> Result<String, TemplateError> {
+        let mut result = String::with_capacity(template.len());
+        let mut chars = template.char_indices().peekable();
+
+        while let Some((position, c)) = chars.next() {
+            match c {
+                '{' if chars.next_if(|&(_, c)| c == '{').is_some() => result.push('{'),
+                '}' if chars.next_if(|&(_, c)| c == '}').is_some() => result.push('}'),
+                '}' => return Err(TemplateError::UnmatchedBrace { position }),
+                '{' => {
+                    let mut name = String::new();
+                    loop {
+                        match chars.next() {
+                            Some((_, '}')) => break,
+                            Some((_, c)) => name.push(c),
+                            None => return Err(TemplateError::UnclosedPlaceholder { position }),
+                        }
+                    }
+                    result.push_str(&self.render_placeholder(&name)?);
+                }
+                c => result.push(c),
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn render_placeholder(&self, name: &str) -> Result<String, TemplateError> {
+        let rendered = match name.to_ascii_lowercase().as_str() {
+            "snake" => self.to_snake_case(),
+            "camel" => self.to_camel_case(),
+            "pascal" => self.to_pascal_case(),
+            "kebab" => self.to_kebab_case(),
+            "screaming" => self.to_screaming_snake_case(),
+            "title" => self.to_title_case(),
+            "dot" => self.to_dot_case(),
+            "flat" => self.format(&CaseSpec::new("", WordCase::Lower, WordCase::Lower)),
+            _ => return Err(TemplateError::UnknownPlaceholder(name.to_string())),
+        };
+        Ok(rendered)
+    }
+}
+
+/// Replace every word matching a key of `replacements`, splitting the replacement into words
+fn replace_words(words: Vec<String>, replacements: &[(String, String)]) -> Vec<String> {
+    if replacements.is_empty() {
+        return words;
+    }
+
+    words
+        .into_iter()
+        .flat_map(
+            |word| match replacements.iter().find(|(from, _)| word_eq(&word, from)) {
+                Some((_, to)) => str_split(to.clone()),
+                None => vec![word],
+            },
+        )
+        .collect()
+}
+
+fn merge_single_letters(input: &str, config: &SplitConfig) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    let mut pending = String::new();
+
+    let splits_chunk = |c: char| {
+        (is_separator(c) && !(config.preserve_hyphens && c == '-'))
+            || (config.split_on_slash && c == '/')
+            || (config.split_on_colon && c == ':')
+            || (config.digits_as_separators && c.is_ascii_digit())
+    };
+    for chunk in input.split(splits_chunk) {
+        let mut chunk_words = str_split_custom(chunk, config);
+        match chunk_words.as_mut_slice() {
+            [] => continue,
+            [letter] if letter.len() == 1 && letter.chars().all(|c| c.is_ascii_alphabetic()) => {
+                pending.push_str(letter);
+                continue;
+            }
+            [first, ..] => first.insert_str(0, &std::mem::take(&mut pending)),
+        }
+        words.append(&mut chunk_words);
+    }
+    if !pending.is_empty() {
+        words.push(pending);
+    }
+
+    words
+}
+
+/// Drop the longest matching prefix and suffix, unless that leaves no words
+fn strip_affixes<'a>(input: &'a str, prefixes: &[String], suffixes: &[String]) -> &'a str {
+    let prefix = prefixes
+        .iter()
+        .filter(|prefix| !prefix.is_empty() && input.starts_with(prefix.as_str()))
+        .filter(|prefix| {
+            // Without a trailing separator, the prefix must end at a case boundary
+            prefix.ends_with(is_separator)
+                || input[prefix.len()..].starts_with(|c: char| c.is_uppercase())
+        })
+        .max_by_key(|prefix| prefix.len());
+    let stripped = prefix.map_or(input, |prefix| &input[prefix.len()..]);
+
+    let suffix = suffixes
+        .iter()
+        .filter(|suffix| !suffix.is_empty() && stripped.ends_with(suffix.as_str()))
+        .filter(|suffix| {
+            // Without a leading separator, the suffix must start at a case boundary
+            let rest = &stripped[..stripped.len() - suffix.len()];
+            suffix.starts_with(is_separator)
+                || (suffix.starts_with(|c: char| c.is_uppercase())
+                    && rest.ends_with(|c: char| !c.is_uppercase()))
+        })
+        .max_by_key(|suffix| suffix.len());
+    let stripped = suffix.map_or(stripped, |suffix| {
+        &stripped[..stripped.len() - suffix.len()]
+    });
+
+    if str_split(stripped.to_string()).is_empty() {
+        input
+    } else {
+        stripped
+    }
+}
+
+/// Compare two words, ignoring case
+fn word_eq(a: &str, b: &str) -> bool {
+    a == b || a.to_lowercase() == b.to_lowercase()
+}
+
+/// Capitalize the letters following the leading digit run of a word (`2d` -> `2D`)
+fn capitalize_after_leading_digits(word: &str) -> String {
+    let split = word
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(word.len());
+    let (digits, rest) = word.split_at(split);
+    let mut chars = rest.chars();
+    match chars.next() {
+        Some(first) => format!(
+            "{}{}{}",
+            digits,
+            first.to_uppercase(),
+            chars.as_str().to_lowercase()
+        ),
+        None => digits.to_string(),
+    }
+}
+
+/// 32-bit FNV-1a hash
+fn fnv1a(bytes: &[u8]) -> u32 {
+    let mut hash: u32 = 0x811c_9dc5;
+    for &b in bytes {
+        hash ^= b as u32;
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+    hash
+}
+
+/// Options for [`CaseFormatter::to_title_case_custom`]
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct TitleCaseOptions {
+    /// Words kept lowercase unless they are the first or last word, see [`StopWordList`]
+    pub stop_words: Vec<String>,
+
+    /// Whether to emit roman numerals and known acronyms fully uppercase (`ii` -> `II`).
+    /// All-caps tokens take precedence over stop words.
+    pub preserve_all_caps_tokens: bool,
+
+    /// Largest roman numeral recognized as an all-caps token.
+    /// Only canonical numerals match, so with the default bound of 39 (`XXXIX`)
+    /// words like `mix` or `liv` stay regular words.
+    pub max_roman_numeral: u32,
+
+    /// Words always emitted uppercase (`nasa` -> `NASA`), compared ignoring case
+    pub acronyms: Vec<String>,
+}
+
+impl Default for TitleCaseOptions {
+    fn default() -> Self {
+        Self {
+            stop_words: Vec::new(),
+            preserve_all_caps_tokens: true,
+            max_roman_numeral: 39,
+            acronyms: Vec::new(),
+        }
+    }
+}
+
+impl TitleCaseOptions {
+    fn is_all_caps_token(&self, word: &str) -> bool {
+        self.acronyms.iter().any(|acronym| word_eq(word, acronym))
+            || roman_numeral_value(word).is_some_and(|value| value <= self.max_roman_numeral)
+    }
+}
+
+/// Options for [`CaseFormatter::to_name_case_custom`]
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct NameCaseOptions {
+    /// Particles kept lowercase unless they are the first word (`van`, `de`, `von`, `der`, `da`),
+    /// compared ignoring case
+    pub particles: Vec<String>,
+
+    /// Whether to capitalize the letter after a `Mc` or `Mac` prefix (`McDonald`, `MacLeod`).
+    /// `Mac` only counts as a prefix when at least three letters follow it, so `Macy` and `Mack`
+    /// are left alone; longer false positives are listed in `mac_exceptions`.
+    pub mc_mac_prefixes: bool,
+
+    /// Names never treated as `Mac` + name (`Machado`, `Macias`), compared ignoring case
+    pub mac_exceptions: Vec<String>,
+}
+
+impl Default for NameCaseOptions {
+    fn default() -> Self {
+        Self {
+            particles: [
+                "van", "de", "von", "der", "da", "den", "del", "della", "di", "du", "la", "le",
+            ]
+            .iter()
+            .map(|p| p.to_string())
+            .collect(),
+            mc_mac_prefixes: true,
+            mac_exceptions: [
+                "machado", "macias", "macedo", "mackey", "mackie", "macon", "machin",
+            ]
+            .iter()
+            .map(|e| e.to_string())
+            .collect(),
+        }
+    }
+}
+
+impl NameCaseOptions {
+    /// Case a single hyphen-free name part, capitalizing after apostrophes and `Mc`/`Mac`
+    fn name_part_case(&self, part: &str) -> String {
+        let part = part.to_lowercase();
+        let prefix_len = if !self.mc_mac_prefixes {
+            0
+        } else if part.starts_with("mc") && part.len() > 2 {
+            2
+        } else if part.starts_with("mac")
+            && part.len() > 5
+            && !self.mac_exceptions.iter().any(|e| word_eq(&part, e))
+        {
+            3
+        } else {
+            0
+        };
+
+        let (prefix, rest) = part.split_at(prefix_len);
+        let rest = rest
+            .split('\'')
+            .map(capitalize)
+            .collect::<Vec<_>>()
+            .join("'");
+        format!("{}{}", capitalize(prefix), rest)
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Parse a canonical roman numeral (`xiv` -> 14), ignoring case
+fn roman_numeral_value(word: &str) -> Option<u32> {
+    const NUMERALS: [(&str, u32); 13] = [
+        ("m", 1000),
+        ("cm", 900),
+        ("d", 500),
+        ("cd", 400),
+        ("c", 100),
+        ("xc", 90),
+        ("l", 50),
+        ("xl", 40),
+        ("x", 10),
+        ("ix", 9),
+        ("v", 5),
+        ("iv", 4),
+        ("i", 1),
+    ];
+
+    if word.is_empty() || word.len() > 16 {
+        return None;
+    }
+    let word = word.to_ascii_lowercase();
+    let mut rest = word.as_str();
+    let mut value = 0;
+    for (numeral, numeral_value) in NUMERALS {
+        while let Some(stripped) = rest.strip_prefix(numeral) {
+            rest = stripped;
+            value += numeral_value;
+        }
+    }
+    if !rest.is_empty() {
+        return None;
+    }
+
+    // Only accept the canonical spelling, rejecting forms like `iiii` or `ixi`
+    let mut canonical = String::new();
+    let mut remaining = value;
+    for (numeral, numeral_value) in NUMERALS {
+        while remaining >= numeral_value {
+            canonical.push_str(numeral);
+            remaining -= numeral_value;
+        }
+    }
+
+    (canonical == word).then_some(value)
+}
+
+/// Options for [`slugify`]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct SlugOptions {
+    /// Whether to transliterate accented Latin letters to ASCII (`é` -> `e`, `ß` -> `ss`)
+    /// before splitting. Otherwise such letters are dropped like any other unsupported character.
+    pub transliterate: bool,
+
+    /// Maximum length of the slug in bytes.
+    /// The slug is cut at the last word boundary that fits; a single overlong word is cut hard.
+    pub max_length: Option<usize>,
+}
+
+impl Default for SlugOptions {
+    fn default() -> Self {
+        Self {
+            transliterate: true,
+            max_length: None,
+        }
+    }
+}
+
+/// Generate a URL-safe slug (brew-the-coffee-2024) from arbitrary text
+///
+/// The slug only contains `[a-z0-9-]`, never starts or ends with `-`,
+/// and never contains repeated hyphens.
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::fmt_case_style::{slugify, SlugOptions};
+/// assert_eq!(slugify("Crème Brûlée!", &SlugOptions::default()), "creme-brulee");
+///
+/// let options = SlugOptions {
+///     max_length: Some(12),
+///     ..Default::default()
+/// };
+/// assert_eq!(slugify("Brew the Coffee", &options), "brew-the");
+/// ```
+pub fn slugify(input: &str, options: &SlugOptions) -> String {
+    let formatter = if options.transliterate {
+        CaseFormatter::from(transliterate(input))
+    } else {
+        CaseFormatter::from(input)
+    };
+    join_slug(&formatter.content, options.max_length)
+}
+
+/// Join words with `-`, keeping whole words within `max_length` bytes
+///
+/// Words are filtered to `[a-z0-9]` first, as custom splits and `from_words` may keep other characters.
+fn join_slug(words: &[String], max_length: Option<usize>) -> String {
+    let max_length = max_length.unwrap_or(usize::MAX);
+    let mut result = String::new();
+
+    for word in words {
+        let word: String = word
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        if word.is_empty() {
+            continue;
+        }
+        let separator = if result.is_empty() { 0 } else { 1 };
+        if result.len() + separator + word.len() > max_length {
+            if result.is_empty() {
+                // Words are ASCII, so any byte index is a char boundary
+                result.push_str(&word[..max_length.min(word.len())]);
+            }
+            break;
+        }
+        if separator == 1 {
+            result.push('-');
+        }
+        result.push_str(&word);
+    }
+
+    result
+}
+
+/// Replace accented Latin letters with their closest ASCII spelling
+fn transliterate(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+    for c in input.chars() {
+        let replacement = match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+            'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' | 'Ā' | 'Ă' | 'Ą' => "A",
+            'æ' => "ae",
+            'Æ' => "AE",
+            'ç' | 'ć' | 'č' => "c",
+            'Ç' | 'Ć' | 'Č' => "C",
+            'ď' | 'đ' => "d",
+            'Ď' | 'Đ' => "D",
+            'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => "e",
+            'È' | 'É' | 'Ê' | 'Ë' | 'Ē' | 'Ė' | 'Ę' | 'Ě' => "E",
+            'ğ' => "g",
+            'Ğ' => "G",
+            'ì' | 'í' | 'î' | 'ï' | 'ī' | 'į' | 'ı' => "i",
+            'Ì' | 'Í' | 'Î' | 'Ï' | 'Ī' | 'Į' | 'İ' => "I",
+            'ł' | 'ľ' => "l",
+            'Ł' | 'Ľ' => "L",
+            'ñ' | 'ń' | 'ň' => "n",
+            'Ñ' | 'Ń' | 'Ň' => "N",
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => "o",
+            'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' | 'Ō' | 'Ő' => "O",
+            'œ' => "oe",
+            'Œ' => "OE",
+            'ř' => "r",
+            'Ř' => "R",
+            'ś' | 'š' | 'ş' => "s",
+            'Ś' | 'Š' | 'Ş' => "S",
+            'ß' => "ss",
+            'ť' | 'ţ' => "t",
+            'Ť' | 'Ţ' => "T",
+            'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' | 'ų' => "u",
+            'Ù' | 'Ú' | 'Û' | 'Ü' | 'Ū' | 'Ů' | 'Ű' | 'Ų' => "U",
+            'ý' | 'ÿ' => "y",
+            'Ý' | 'Ÿ' => "Y",
+            'ź' | 'ż' | 'ž' => "z",
+            'Ź' | 'Ż' | 'Ž' => "Z",
+            _ => {
+                result.push(c);
+                continue;
+            }
+        };
+        result.push_str(replacement);
+    }
+    result
+}
+
+/// Cut the string to at most `max_bytes` bytes without splitting a code point
+fn truncate_at_char_boundary(input: &str, max_bytes: usize) -> &str {
+    if input.len() <= max_bytes {
+        return input;
+    }
+    let mut end = max_bytes;
+    while !input.is_char_boundary(end) {
+        end -= 1;
+    }
+    &input[..end]
+}
+
+/// Render an environment variable prefix in SCREAMING_SNAKE_CASE
+fn env_var_prefix(prefix: &str) -> String {
+    CaseFormatter::from(prefix).to_screaming_snake_case()
+}
+
+/// Convert the identifier inside a token, keeping the surrounding punctuation
+///
+/// Characters other than ASCII alphanumerics and `_` are trimmed from both ends,
+/// the remaining core is converted (keeping its leading and trailing underscores),
+/// and the exact prefix and suffix are re-attached.
+/// A token without any identifier core is returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::fmt_case_style::{Case, convert_embedded};
+/// assert_eq!(convert_embedded("fooBar()", Case::Snake), "foo_bar()");
+/// assert_eq!(convert_embedded("\"userName\",", Case::Kebab), "\"user-name\",");
+/// ```
+pub fn convert_embedded(token: &str, case: Case) -> String {
+    let is_ident_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let Some(start) = token.find(is_ident_char) else {
+        return token.to_string();
+    };
+    let end = token.rfind(is_ident_char).map_or(start, |i| i + 1);
+
+    let config = SplitConfig {
+        preserve_underscores: true,
+        ..Default::default()
+    };
+    let core = CaseFormatter::from_custom(&token[start..end], &config).convert(case);
+    format!("{}{}{}", &token[..start], core, &token[end..])
+}
+
+/// Word ordering used by [`CaseFormatter::sort_words_in`]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash, Default)]
+pub enum WordOrder {
+    /// Plain string order (`item10` < `item2`)
+    #[default]
+    Lexicographic,
+
+    /// Digit runs compare by numeric value (`item2` < `item10`).
+    /// Runs with the same value but different zero padding fall back to string order,
+    /// so `item002` sorts before `item2`.
+    Natural,
+}
+
+impl WordOrder {
+    /// Compare two words in this order
+    pub fn compare(self, a: &str, b: &str) -> std::cmp::Ordering {
+        match self {
+            WordOrder::Lexicographic => a.cmp(b),
+            WordOrder::Natural => cmp_natural(a, b).then_with(|| a.cmp(b)),
+        }
+    }
+}
+
+/// Compare two names word by word in natural order, ignoring their case style
+///
+/// Both names are split like [`CaseFormatter::from`] and compared as alternating digit and
+/// letter runs, where a word boundary also ends a run. So `item_2`, `item2` and `Item2` compare
+/// equal, and `item_10` sorts after `item_2`. See [`WordOrder::Natural`] for zero padding.
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::fmt_case_style::cmp_natural_ignore_case_style;
+/// let mut names = vec!["item_10", "Item2", "itemAlpha", "item-1"];
+/// names.sort_by(|a, b| cmp_natural_ignore_case_style(a, b));
+/// assert_eq!(names, ["item-1", "Item2", "item_10", "itemAlpha"]);
+/// ```
+pub fn cmp_natural_ignore_case_style(a: &str, b: &str) -> std::cmp::Ordering {
+    let a = CaseFormatter::from(a);
+    let b = CaseFormatter::from(b);
+
+    // A word boundary also ends a run, so `item_2` and `item2` both read as `item`, `2`
+    let runs = |words: &[String]| -> Vec<String> {
+        words
+            .iter()
+            .flat_map(|word| digit_runs(word))
+            .map(str::to_string)
+            .collect()
+    };
+    cmp_natural_runs(
+        runs(a.words()).iter().map(String::as_str),
+        runs(b.words()).iter().map(String::as_str),
+    )
+    .then_with(|| a.words().concat().cmp(&b.words().concat()))
+}
+
+/// Compare alternating digit and non-digit runs, digit runs by numeric value
+fn cmp_natural(a: &str, b: &str) -> std::cmp::Ordering {
+    cmp_natural_runs(digit_runs(a), digit_runs(b))
+}
+
+fn cmp_natural_runs<'a>(
+    mut a_runs: impl Iterator<Item = &'a str>,
+    mut b_runs: impl Iterator<Item = &'a str>,
+) -> std::cmp::Ordering {
+    loop {
+        let ordering = match (a_runs.next(), b_runs.next()) {
+            (None, None) => return std::cmp::Ordering::Equal,
+            (None, Some(_)) => return std::cmp::Ordering::Less,
+            (Some(_), None) => return std::cmp::Ordering::Greater,
+            (Some(a), Some(b))
+                if a.starts_with(|c: char| c.is_ascii_digit())
+                    && b.starts_with(|c: char| c.is_ascii_digit()) =>
+            {
+                // Compare by value without parsing, so arbitrarily long runs cannot overflow
+                let a = a.trim_start_matches('0');
+                let b = b.trim_start_matches('0');
+                a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+            }
+            (Some(a), Some(b)) => a.cmp(b),
+        };
+        if ordering.is_ne() {
+            return ordering;
+        }
+    }
+}
+
+/// Split a word into alternating runs of ASCII digits and other characters
+fn digit_runs(word: &str) -> impl Iterator<Item = &str> {
+    let mut rest = word;
+    std::iter::from_fn(move || {
+        let first = rest.chars().next()?;
+        let is_digit = first.is_ascii_digit();
+        let end = rest
+            .find(|c: char| c.is_ascii_digit() != is_digit)
+            .unwrap_or(rest.len());
+        let (run, tail) = rest.split_at(end);
+        rest = tail;
+        Some(run)
+    })
+}
+
+/// Target language for [`is_valid_identifier`]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub enum IdentLang {
+    /// Rust, with the keywords reserved by the 2024 edition
+    Rust,
+
+    /// JavaScript, including the keywords reserved in strict mode
+    Js,
+
+    /// Python 3
+    Python,
+}
+
+impl IdentLang {
+    /// Keywords that cannot be used as identifiers
+    pub fn keywords(self) -> &'static [&'static str] {
+        match self {
+            IdentLang::Rust => &[
+                "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false",
+                "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut",
+                "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait",
+                "true", "type", "unsafe", "use", "where", "while", "async", "await", "dyn",
+                "abstract", "become", "box", "do", "final", "gen", "macro", "override", "priv",
+                "try", "typeof", "unsized", "virtual", "yield",
+            ],
+            IdentLang::Js => &[
+                "await",
+                "break",
+                "case",
+                "catch",
+                "class",
+                "const",
+                "continue",
+                "debugger",
+                "default",
+                "delete",
+                "do",
+                "else",
+                "enum",
+                "export",
+                "extends",
+                "false",
+                "finally",
+                "for",
+                "function",
+                "if",
+                "implements",
+                "import",
+                "in",
+                "instanceof",
+                "interface",
+                "let",
+                "new",
+                "null",
+                "package",
+                "private",
+                "protected",
+                "public",
+                "return",
+                "static",
+                "super",
+                "switch",
+                "this",
+                "throw",
+                "true",
+                "try",
+                "typeof",
+                "var",
+                "void",
+                "while",
+                "with",
+                "yield",
+            ],
+            IdentLang::Python => &[
+                "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class",
+                "continue", "def", "del", "elif", "else", "except", "finally", "for", "from",
+                "global", "if", "import", "in", "is", "lambda", "nonlocal", "not", "or", "pass",
+                "raise", "return", "try", "while", "with", "yield",
+            ],
+        }
+    }
+}
+
+/// Check whether `s` can be used as an identifier in the given language
+///
+/// The first character must be a letter or `_` (or `$` in JavaScript), the rest letters,
+/// digits or `_` (or `$`), and the identifier must not be a keyword.
+/// A lone `_` is not a valid Rust identifier.
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::fmt_case_style::{CaseFormatter, IdentLang, is_valid_identifier};
+/// let name = CaseFormatter::from("user_name").to_camel_case();
+/// assert!(is_valid_identifier(&name, IdentLang::Js));
+///
+/// let name = CaseFormatter::from("2fa_code").to_snake_case();
+/// assert!(!is_valid_identifier(&name, IdentLang::Rust));
+/// assert!(!is_valid_identifier("match", IdentLang::Rust));
+/// ```
+pub fn is_valid_identifier(s: &str, lang: IdentLang) -> bool {
+    let extra = |c: char| c == '_' || (lang == IdentLang::Js && c == '$');
+    let mut chars = s.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    if !(first.is_alphabetic() || extra(first)) || !chars.all(|c| c.is_alphanumeric() || extra(c)) {
+        return false;
+    }
+    if lang == IdentLang::Rust && s == "_" {
+        return false;
+    }
+    !lang.keywords().contains(&s)
+}
+
+/// Check whether the token is already written in the given case style
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::fmt_case_style::{Case, matches_case};
+/// assert!(matches_case("brew_coffee", Case::Snake));
+/// assert!(!matches_case("brewCoffee", Case::Snake));
+/// ```
+pub fn matches_case(token: &str, case: Case) -> bool {
+    !token.is_empty() && CaseFormatter::from(token).convert(case) == token
+}
+
+/// Convert every identifier in `text` written in the `from` style into the `to` style
+///
+/// Identifiers are runs of `[A-Za-z0-9_]`, plus `-` when `from` is [`Case::Kebab`].
+/// Tokens that do not match `from`, and everything between tokens, are kept byte-identical.
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::fmt_case_style::{Case, recase_tokens};
+/// assert_eq!(
+///     recase_tokens("SELECT user_id FROM orders;", Case::Snake, Case::Camel),
+///     "SELECT userId FROM orders;"
+/// );
+/// ```
+pub fn recase_tokens(text: &str, from: Case, to: Case) -> String {
+    map_tokens(text, from == Case::Kebab, &mut |token| {
+        matches_case(token, from).then(|| CaseFormatter::from(token).convert(to))
+    })
+}
+
+/// List the spellings of `name` in the case styles commonly used for identifiers
+///
+/// The styles are camelCase, PascalCase, snake_case, SCREAMING_SNAKE_CASE and kebab-case.
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::fmt_case_style::{Case, style_variants};
+/// let variants = style_variants("brew_coffee");
+/// assert!(variants.contains(&(Case::Pascal, "BrewCoffee".to_string())));
+/// assert!(variants.contains(&(Case::ScreamingSnake, "BREW_COFFEE".to_string())));
+/// ```
+pub fn style_variants(name: &str) -> Vec<(Case, String)> {
+    let formatter = CaseFormatter::from(name);
+    [
+        Case::Camel,
+        Case::Pascal,
+        Case::Snake,
+        Case::ScreamingSnake,
+        Case::Kebab,
+    ]
+    .into_iter()
+    .map(|case| (case, formatter.convert(case)))
+    .collect()
+}
+
+/// Rename every identifier spelled as a style variant of `old` to the same style of `new`
+///
+/// Only whole identifiers are replaced, so `old_name_extra` is left alone when renaming `old_name`.
+/// Hyphenated runs are first matched as a whole (kebab-case), then part by part.
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::fmt_case_style::rename_all_styles;
+/// assert_eq!(
+///     rename_all_styles("let old_name = OldName::new(OLD_NAME);", "oldName", "newName"),
+///     "let new_name = NewName::new(NEW_NAME);"
+/// );
+/// ```
+pub fn rename_all_styles(text: &str, old: &str, new: &str) -> String {
+    let new = CaseFormatter::from(new);
+    let mut replacements: Vec<(String, String)> = Vec::new();
+    for (case, from) in style_variants(old) {
+        if !from.is_empty() && !replacements.iter().any(|(f, _)| *f == from) {
+            replacements.push((from, new.convert(case)));
+        }
+    }
+
+    map_tokens(text, true, &mut |token| {
+        replacements
+            .iter()
+            .find(|(from, _)| from == token)
+            .map(|(_, to)| to.clone())
+    })
+}
+
+/// Replace identifier tokens in `text`, keeping everything else byte-identical
+///
+/// Tokens are runs of `[A-Za-z0-9_]`, plus `-` when `hyphenated` is set.
+/// When a hyphenated token has no replacement, its hyphen-free parts are tried one by one.
+fn map_tokens(
+    text: &str,
+    hyphenated: bool,
+    replace: &mut impl FnMut(&str) -> Option<String>,
+) -> String {
+    let is_token_char = |c: char| c.is_ascii_alphanumeric() || c == '_' || (hyphenated && c == '-');
+
+    let mut result = String::with_capacity(text.len());
+    let mut token_start = None;
+
+    for (i, c) in text.char_indices() {
+        match (is_token_char(c), token_start) {
+            (true, None) => token_start = Some(i),
+            (false, Some(start)) => {
+                push_token(&mut result, &text[start..i], hyphenated, replace);
+                token_start = None;
+                result.push(c);
+            }
+            (false, None) => result.push(c),
+            (true, Some(_)) => {}
+        }
+    }
+    if let Some(start) = token_start {
+        push_token(&mut result, &text[start..], hyphenated, replace);
+    }
+
+    result
+}
+
+fn push_token(
+    result: &mut String,
+    token: &str,
+    hyphenated: bool,
+    replace: &mut impl FnMut(&str) -> Option<String>,
+) {
+    match replace(token) {
+        Some(replacement) => result.push_str(&replacement),
+        None if hyphenated && token.contains('-') => {
+            result.push_str(&map_tokens(token, false, replace));
+        }
+        None => result.push_str(token),
+    }
+}
+
+/// Convert to camelCase format (brewCoffee)
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::camel_case;
+/// assert_eq!(camel_case!("brew_coffee"), "brewCoffee");
+/// ```
+#[macro_export]
+macro_rules! camel_case {
+    ($input:expr) => {{
+        use just_fmt::fmt_case_style::CaseFormatter;
+        CaseFormatter::from($input).to_camel_case()
+    }};
+}
+
+/// Convert to UPPER CASE format (BREW COFFEE)
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::upper_case;
+/// assert_eq!(upper_case!("brew coffee"), "BREW COFFEE");
+/// ```
+#[macro_export]
+macro_rules! upper_case {
+    ($input:expr) => {{
+        use just_fmt::fmt_case_style::CaseFormatter;
+        CaseFormatter::from($input).to_upper_case()
+    }};
+}
+
+/// Convert to lower case format (brew coffee)
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::lower_case;
+/// assert_eq!(lower_case!("BREW COFFEE"), "brew coffee");
+/// ```
+#[macro_export]
+macro_rules! lower_case {
+    ($input:expr) => {{
+        use just_fmt::fmt_case_style::CaseFormatter;
+        CaseFormatter::from($input).to_lower_case()
+    }};
+}
+
+/// Convert to Title Case format (Brew Coffee)
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::title_case;
+/// assert_eq!(title_case!("brew_coffee"), "Brew Coffee");
+/// ```
+#[macro_export]
+macro_rules! title_case {
+    ($input:expr) => {{
+        use just_fmt::fmt_case_style::CaseFormatter;
+        CaseFormatter::from($input).to_title_case()
+    }};
+}
+
+/// Convert to dot.case format (brew.coffee)
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::dot_case;
+/// assert_eq!(dot_case!("brew_coffee"), "brew.coffee");
+/// ```
+#[macro_export]
+macro_rules! dot_case {
+    ($input:expr) => {{
+        use just_fmt::fmt_case_style::CaseFormatter;
+        CaseFormatter::from($input).to_dot_case()
+    }};
+}
+
+/// Convert to path/case format (brew/coffee)
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::path_case;
+/// assert_eq!(path_case!("brew_coffee"), "brew/coffee");
+/// ```
+#[macro_export]
+macro_rules! path_case {
+    ($input:expr) => {{
+        use just_fmt::fmt_case_style::CaseFormatter;
+        CaseFormatter::from($input).to_path_case()
+    }};
+}
+
+/// Convert to snake_case format (brew_coffee)
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::snake_case;
+/// assert_eq!(snake_case!("brewCoffee"), "brew_coffee");
+/// ```
+#[macro_export]
+macro_rules! snake_case {
+    ($input:expr) => {{
+        use just_fmt::fmt_case_style::CaseFormatter;
+        CaseFormatter::from($input).to_snake_case()
+    }};
+}
+
+/// Convert to SCREAMING_SNAKE_CASE format (BREW_COFFEE)
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::screaming_snake_case;
+/// assert_eq!(screaming_snake_case!("brewCoffee"), "BREW_COFFEE");
+/// ```
+#[macro_export]
+macro_rules! screaming_snake_case {
+    ($input:expr) => {{
+        use just_fmt::fmt_case_style::CaseFormatter;
+        CaseFormatter::from($input).to_screaming_snake_case()
+    }};
+}
+
+/// Convert to kebab-case format (brew-coffee)
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::kebab_case;
+/// assert_eq!(kebab_case!("brew_coffee"), "brew-coffee");
+/// ```
+#[macro_export]
+macro_rules! kebab_case {
+    ($input:expr) => {{
+        use just_fmt::fmt_case_style::CaseFormatter;
+        CaseFormatter::from($input).to_kebab_case()
+    }};
+}
+
+/// Generate a URL slug (brew-the-coffee)
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::slug;
+/// # use just_fmt::fmt_case_style::SlugOptions;
+/// assert_eq!(slug!("Brew — the Coffee!"), "brew-the-coffee");
+///
+/// let options = SlugOptions {
+///     max_length: Some(8),
+///     ..Default::default()
+/// };
+/// assert_eq!(slug!("Brew the Coffee", &options), "brew-the");
+/// ```
+#[macro_export]
+macro_rules! slug {
+    ($input:expr) => {{
+        use just_fmt::fmt_case_style::{SlugOptions, slugify};
+        slugify($input, &SlugOptions::default())
+    }};
+    ($input:expr, $options:expr) => {{
+        use just_fmt::fmt_case_style::slugify;
+        slugify($input, $options)
+    }};
+}
+
+/// Convert to SCREAMING-KEBAB-CASE format (BREW-COFFEE)
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::screaming_kebab_case;
+/// assert_eq!(screaming_kebab_case!("brew_coffee"), "BREW-COFFEE");
+/// ```
+#[macro_export]
+macro_rules! screaming_kebab_case {
+    ($input:expr) => {{
+        use just_fmt::fmt_case_style::CaseFormatter;
+        CaseFormatter::from($input).to_kebab_case_upper()
+    }};
+}
+
+/// Convert to a camelCase getter name (getBrewCoffee)
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::getter;
+/// assert_eq!(getter!("brew_coffee"), "getBrewCoffee");
+/// ```
+#[macro_export]
+macro_rules! getter {
+    ($input:expr) => {{
+        use just_fmt::fmt_case_style::CaseFormatter;
+        CaseFormatter::from($input).to_getter()
+    }};
+}
+
+/// Convert to a camelCase setter name (setBrewCoffee)
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::setter;
+/// assert_eq!(setter!("brew_coffee"), "setBrewCoffee");
+/// ```
+#[macro_export]
+macro_rules! setter {
+    ($input:expr) => {{
+        use just_fmt::fmt_case_style::CaseFormatter;
+        CaseFormatter::from($input).to_setter()
+    }};
+}
+
+/// Convert to PascalCase format (BrewCoffee)
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::pascal_case;
+/// assert_eq!(pascal_case!("brew_coffee"), "BrewCoffee");
+/// ```
+#[macro_export]
+macro_rules! pascal_case {
+    ($input:expr) => {{
+        use just_fmt::fmt_case_style::CaseFormatter;
+        CaseFormatter::from($input).to_pascal_case()
+    }};
+}
+
+/// Convert to an environment variable name (MYAPP_BREW_COFFEE), with an optional prefix
+///
+/// Expands to [`CaseFormatter::to_env_var`](crate::fmt_case_style::CaseFormatter::to_env_var),
+/// passing `None` when no prefix is given.
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::env_var;
+/// assert_eq!(env_var!("database_url"), "DATABASE_URL");
+/// assert_eq!(env_var!("database.url", "myapp"), "MYAPP_DATABASE_URL");
+/// ```
+#[macro_export]
+macro_rules! env_var {
+    ($input:expr) => {{
+        use just_fmt::fmt_case_style::CaseFormatter;
+        CaseFormatter::from($input).to_env_var(None)
+    }};
+    ($input:expr, $prefix:expr) => {{
+        use just_fmt::fmt_case_style::CaseFormatter;
+        CaseFormatter::from($input).to_env_var(Some($prefix))
+    }};
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Case {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.choose(&ALL_CASES).copied()
+    }
+}
+
+/// Generates the word list directly: up to 6 words of 2 to 8 lowercase ASCII letters,
+/// so every conversion splits back into the same words.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CaseFormatter {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let count = u.int_in_range(0..=6)?;
+        let mut words = Vec::with_capacity(count);
+        for _ in 0..count {
+            let len = u.int_in_range(2..=8)?;
+            let mut word = String::with_capacity(len);
+            for _ in 0..len {
+                word.push(u.int_in_range(b'a'..=b'z')? as char);
+            }
+            words.push(word);
+        }
+        Ok(Self::with_content(words))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::fmt_case_style::{
+        CapitalizationMode, Case, CaseConvert, CaseError, CaseFormatter, CaseParseError, CaseSpec,
+        IdentLang, JsonNamingConvention, NameCaseOptions, SlugOptions, SplitConfig, StopWordList,
+        StrictCaseError, StrictCaseFormatter, TemplateError, TitleCaseOptions, Utf8CaseError,
+        WordCase, WordOrder, cmp_natural_ignore_case_style, convert_embedded, is_valid_identifier,
+        recase_tokens, rename_all_styles, slugify, str_split_custom, str_split_preserving_case,
+    };
+
+    #[test]
+    fn test_processer() {
+        let test_cases = vec![
+            ("brew_coffee", "brewCoffee"),
+            ("brew, coffee", "brewCoffee"),
+            ("brew-coffee", "brewCoffee"),
+            ("Brew.Coffee", "brewCoffee"),
+            ("bRewCofFee", "bRewCofFee"),
+            ("brewCoffee", "brewCoffee"),
+            ("b&rewCoffee", "brewCoffee"),
+            ("BrewCoffee", "brewCoffee"),
+            ("brew.coffee", "brewCoffee"),
+            ("Brew_Coffee", "brewCoffee"),
+            ("BREW COFFEE", "brewCoffee"),
+        ];
+
+        for (input, expected) in test_cases {
+            let processor = CaseFormatter::from(input);
+            assert_eq!(
+                processor.to_camel_case(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_conversions() {
+        let processor = CaseFormatter::from("brewCoffee");
+
+        assert_eq!(processor.to_upper_case(), "BREW COFFEE");
+        assert_eq!(processor.to_lower_case(), "brew coffee");
+        assert_eq!(processor.to_title_case(), "Brew Coffee");
+        assert_eq!(processor.to_dot_case(), "brew.coffee");
+        assert_eq!(processor.to_snake_case(), "brew_coffee");
+        assert_eq!(processor.to_kebab_case(), "brew-coffee");
+        assert_eq!(processor.to_pascal_case(), "BrewCoffee");
+        assert_eq!(processor.to_camel_case(), "brewCoffee");
+    }
+
+    #[test]
+    fn test_screaming_kebab_case() {
+        let processor = CaseFormatter::from("brewCoffee");
+
+        assert_eq!(processor.to_kebab_case_upper(), "BREW-COFFEE");
+        assert_eq!(processor.to_screaming_kebab_case(), "BREW-COFFEE");
+    }
+
+    #[test]
+    fn test_numeric_suffix_words() {
+        let test_cases = vec![
+            ("html5_parser", "html5Parser", "Html5Parser"),
+            ("parser_v2", "parserV2", "ParserV2"),
+            ("sha256_hash", "sha256Hash", "Sha256Hash"),
+        ];
+
+        for (input, expected_camel, expected_pascal) in test_cases {
+            let processor = CaseFormatter::from(input);
+            assert_eq!(processor.to_camel_case(), expected_camel);
+            assert_eq!(processor.to_pascal_case(), expected_pascal);
+        }
+    }
+
+    #[test]
+    fn test_recase_tokens() {
+        let input = "SELECT user_id, order_total\n\
+                     FROM customer_orders -- keep camelCase\n\
+                     WHERE total>10 AND _private=1;\n";
+        let expected = "SELECT userId, orderTotal\n\
+                        FROM customerOrders -- keep camelCase\n\
+                        WHERE total>10 AND _private=1;\n";
+        assert_eq!(recase_tokens(input, Case::Snake, Case::Camel), expected);
+
+        assert_eq!(
+            recase_tokens("(brew-coffee) brewCoffee", Case::Kebab, Case::Snake),
+            "(brew_coffee) brewCoffee"
+        );
+        assert_eq!(
+            recase_tokens("brewCoffee é café_au_lait", Case::Camel, Case::Kebab),
+            "brew-coffee é café_au_lait"
+        );
+    }
+
+    #[test]
+    fn test_rename_all_styles() {
+        let input = "struct OldName { old_name: u32 }\n\
+                     const OLD_NAME: &str = \"old-name\";\n\
+                     fn oldName(old_name_extra: OldNameExtra) {}\n";
+        let expected = "struct NewName { new_name: u32 }\n\
+                        const NEW_NAME: &str = \"new-name\";\n\
+                        fn newName(old_name_extra: OldNameExtra) {}\n";
+        assert_eq!(rename_all_styles(input, "old_name", "new_name"), expected);
+
+        assert_eq!(
+            rename_all_styles("a-old-name old-name-b x-oldName", "old name", "new name"),
+            "a-old-name old-name-b x-newName"
+        );
+    }
+
+    #[test]
+    fn test_env_var() {
+        let processor = CaseFormatter::from("database.pool.maxSize");
+        let name = processor.to_env_var(Some("MYAPP"));
+        assert_eq!(name, "MYAPP_DATABASE_POOL_MAX_SIZE");
+        assert_eq!(
+            processor.to_env_var(Some("my-app!")),
+            "MY_APP_DATABASE_POOL_MAX_SIZE"
+        );
+        assert_eq!(
+            CaseFormatter::from("2fa_code").to_env_var(None),
+            "_2FA_CODE"
+        );
+        assert_eq!(CaseFormatter::from("").to_env_var(Some("myapp")), "MYAPP");
+
+        for input in ["database_url", "maxPoolSize", "api.v2.key"] {
+            let processor = CaseFormatter::from(input);
+            assert_eq!(
+                processor.to_env_var(None),
+                processor.to_screaming_snake_case(),
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let round_trip = CaseFormatter::from_env_var(&name, Some("MYAPP"));
+        assert_eq!(round_trip.to_dot_case(), "database.pool.max.size");
+        assert_eq!(round_trip.to_env_var(Some("MYAPP")), name);
+
+        // Names without the prefix are kept whole
+        let unprefixed = CaseFormatter::from_env_var("OTHER_URL", Some("MYAPP"));
+        assert_eq!(unprefixed.to_snake_case(), "other_url");
+    }
+
+    #[test]
+    fn test_mixed_separator_runs() {
+        let test_cases: Vec<(&str, Vec<&str>)> = vec![
+            ("brew_.-,coffee", vec!["brew", "coffee"]),
+            ("a_.,- _b", vec!["a", "b"]),
+            ("...a...", vec!["a"]),
+            ("---", vec![]),
+            ("", vec![]),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                CaseFormatter::from(input).content,
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_slugify() {
+        let options = SlugOptions::default();
+        let test_cases = vec![
+            ("Brew — the Coffee! (2024)", "brew-the-coffee-2024"),
+            ("  --Hello,,, World?!--  ", "hello-world"),
+            ("Crème Brûlée à la Française", "creme-brulee-a-la-francaise"),
+            ("Straße Ærø", "strasse-aero"),
+            ("Coffee ☕ Time 🚀", "coffee-time"),
+            ("☕🚀", ""),
+        ];
+        for (input, expected) in test_cases {
+            assert_eq!(
+                slugify(input, &options),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let no_transliterate = SlugOptions {
+            transliterate: false,
+            ..Default::default()
+        };
+        assert_eq!(slugify("Crème Brûlée", &no_transliterate), "crme-brle");
+
+        let limited = |max_length| SlugOptions {
+            max_length: Some(max_length),
+            ..Default::default()
+        };
+        assert_eq!(slugify("brew the coffee", &limited(15)), "brew-the-coffee");
+        assert_eq!(slugify("brew the coffee", &limited(14)), "brew-the");
+        assert_eq!(slugify("brew the coffee", &limited(4)), "brew");
+        assert_eq!(slugify("espresso", &limited(3)), "esp");
+        assert_eq!(slugify("brew", &limited(0)), "");
+    }
+
+    #[test]
+    fn test_to_slug_filters_words() {
+        let config = SplitConfig {
+            preserve_apostrophes: true,
+            ..Default::default()
+        };
+        let processor = CaseFormatter::from_custom("Don't Stop", &config);
+        assert_eq!(processor.to_snake_case(), "don't_stop");
+        assert_eq!(processor.to_slug(), "dont-stop");
+
+        let processor = CaseFormatter::from_words(vec![
+            "@Café".to_string(),
+            "☕".to_string(),
+            "X1".to_string(),
+        ]);
+        assert_eq!(processor.to_slug(), "caf-x1");
+    }
+
+    #[test]
+    fn test_to_filename() {
+        assert_eq!(
+            CaseFormatter::from("Notes: C:\\temp\\*?\"todo\"").to_filename(Some("txt")),
+            "notes_ctemptodo.txt"
+        );
+        assert_eq!(
+            CaseFormatter::from("a/b").to_filename(Some(".tar.gz. ")),
+            "ab.tar.gz"
+        );
+        assert_eq!(
+            CaseFormatter::from("NUL").to_filename(Some("log")),
+            "nul_.log"
+        );
+        assert_eq!(CaseFormatter::from("com1").to_filename(None), "com1_");
+        assert_eq!(CaseFormatter::from("console").to_filename(None), "console");
+        assert_eq!(CaseFormatter::from("!!!").to_filename(Some("md")), "_.md");
+
+        let long_title = "word ".repeat(60);
+        let filename = CaseFormatter::from(long_title.as_str()).to_filename(Some("md"));
+        assert_eq!(filename.len(), 255);
+        assert!(filename.ends_with("_wo.md"));
+
+        let filename = CaseFormatter::from(long_title.as_str()).to_filename(None);
+        assert_eq!(filename.len(), 255);
+
+        // A multi-byte extension is cut on a code point boundary
+        let extension = "é".repeat(200);
+        let filename = CaseFormatter::from("brew coffee").to_filename(Some(&extension));
+        assert_eq!(filename.len(), 255);
+        assert_eq!(filename, format!("br.{}", "é".repeat(126)));
+
+        // A multi-byte stem is cut on a code point boundary too
+        let formatter = CaseFormatter::from_words(vec!["é".repeat(200)]);
+        let filename = formatter.to_filename(Some("txt"));
+        assert_eq!(filename, format!("{}.txt", "é".repeat(125)));
+        let filename = formatter.to_filename(None);
+        assert_eq!(filename, "é".repeat(127));
+
+        // Words that bypass splitting are filtered like the extension
+        let formatter = CaseFormatter::from_words(vec!["a/b".to_string(), "c*d".to_string()]);
+        assert_eq!(formatter.to_filename(None), "ab_cd");
+        let mut formatter = CaseFormatter::from("x");
+        formatter.push_word("..\\evil");
+        assert_eq!(formatter.to_filename(Some("txt")), "x_..evil.txt");
+        let formatter = CaseFormatter::from_words(vec!["..".to_string()]);
+        assert_eq!(formatter.to_filename(None), "_");
+        let formatter = CaseFormatter::from_words(vec!["con.x".to_string()]);
+        assert_eq!(formatter.to_filename(None), "con_.x");
+
+        // A stem cut down to a reserved name is caught
+        let formatter = CaseFormatter::from_words(vec!["con".to_string(), "sole".to_string()]);
+        let extension = "e".repeat(250);
+        assert_eq!(
+            formatter.to_filename(Some(&extension)),
+            format!("con_.{}", extension)
+        );
+    }
+
+    #[test]
+    fn test_from_ascii_bytes() {
+        let inputs = [
+            "brew_coffee",
+            "brewCoffee",
+            "BrewCoffee",
+            "bRewCofFee",
+            "b&rewCoffee",
+            "BREW COFFEE",
+            "a_.,- _b",
+            "...a...",
+            "html5_parser",
+            "XMLHttpRequest",
+            "",
+        ];
+        for input in inputs {
+            let from_bytes = CaseFormatter::from_ascii_bytes(input.as_bytes()).unwrap();
+            assert_eq!(
+                from_bytes.content,
+                CaseFormatter::from(input).content,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        assert_eq!(
+            CaseFormatter::from_ascii_bytes(b"brew\xffcoffee").err(),
+            Some(CaseError::NonAscii {
+                position: 4,
+                byte: 0xff
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_ascii_bytes_bulk() {
+        let identifiers: Vec<String> = (0..10_000)
+            .map(|i| format!("brewCoffee_{}_withMilkAndSugar-{}", i, i * 7))
+            .collect();
+
+        for identifier in &identifiers {
+            let from_bytes = CaseFormatter::from_ascii_bytes(identifier.as_bytes()).unwrap();
+            assert_eq!(
+                from_bytes.to_snake_case(),
+                CaseFormatter::from(identifier).to_snake_case()
+            );
+        }
+    }
+
+    #[test]
+    fn test_leading_digits() {
+        let test_cases = vec![
+            ("123abc", "123Abc", "123Abc"),
+            ("2dGame", "2DGame", "2DGame"),
+            ("3dVector", "3DVector", "3DVector"),
+            ("3d_vector", "3DVector", "3DVector"),
+            ("123", "123", "123"),
+            ("123_456", "123_456", "123_456"),
+            ("version_2d", "version2d", "Version2d"),
+        ];
+
+        for (input, expected_camel, expected_pascal) in test_cases {
+            let processor = CaseFormatter::from(input);
+            assert_eq!(
+                processor.to_camel_case(),
+                expected_camel,
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                processor.to_pascal_case(),
+                expected_pascal,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_case_truncated_unique() {
+        let shared =
+            "the quick brown fox jumps over the lazy dog near the old river bank every day";
+        assert!(shared.len() >= 70);
+
+        let a = CaseFormatter::from(format!("{} alpha", shared));
+        let b = CaseFormatter::from(format!("{} beta", shared));
+
+        for case in [Case::Kebab, Case::Snake, Case::ScreamingSnake, Case::Camel] {
+            let truncated_a = a.to_case_truncated_unique(case, 63);
+            let truncated_b = b.to_case_truncated_unique(case, 63);
+            assert!(truncated_a.len() <= 63 && truncated_b.len() <= 63);
+            assert_ne!(truncated_a, truncated_b);
+            assert_eq!(truncated_a, a.to_case_truncated_unique(case, 63));
+        }
+        assert!(
+            a.to_case_truncated_unique(Case::ScreamingSnake, 63)
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+        );
+
+        let short = CaseFormatter::from("brew_coffee");
+        assert_eq!(
+            short.to_case_truncated_unique(Case::Kebab, 63),
+            "brew-coffee"
+        );
+        assert_eq!(
+            short.to_case_truncated_unique(Case::Kebab, 11),
+            "brew-coffee"
+        );
+        assert_eq!(short.to_case_truncated_unique(Case::Kebab, 4).len(), 4);
+
+        // Multi-byte words are cut on a code point boundary
+        let accented = CaseFormatter::from_words(vec!["é".repeat(20)]);
+        let truncated = accented.to_case_truncated_unique(Case::Snake, 14);
+        assert!(truncated.len() <= 14);
+        assert!(truncated.starts_with("ééé_"));
+    }
+
+    #[test]
+    fn test_preserve_underscores() {
+        let config = SplitConfig {
+            preserve_underscores: true,
+            ..Default::default()
+        };
+        let test_cases = vec![
+            (
+                "_internalValue",
+                "_internal_value",
+                "_internalValue",
+                "_InternalValue",
+            ),
+            (
+                "__private_field",
+                "__private_field",
+                "__privateField",
+                "__PrivateField",
+            ),
+            ("type_", "type_", "type_", "Type_"),
+            ("__init__", "__init__", "__init__", "__Init__"),
+            ("_a_b__", "_a_b__", "_aB__", "_AB__"),
+            ("plain_name", "plain_name", "plainName", "PlainName"),
+        ];
+
+        for (input, expected_snake, expected_camel, expected_pascal) in test_cases {
+            let processor = CaseFormatter::from_custom(input, &config);
+            assert_eq!(
+                processor.to_snake_case(),
+                expected_snake,
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                processor.to_camel_case(),
+                expected_camel,
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                processor.to_pascal_case(),
+                expected_pascal,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        assert_eq!(
+            CaseFormatter::from_custom("__dunder__", &config).to_kebab_case(),
+            "__dunder__"
+        );
+
+        // Disabled by default
+        let processor = CaseFormatter::from_custom("_internal_value", &SplitConfig::default());
+        assert_eq!(processor.to_camel_case(), "internalValue");
+    }
+
+    #[test]
+    fn test_words_round_trip() {
+        for input in ["brewCoffee", "BREW COFFEE", "html5_parser", "a_.,- _b", ""] {
+            let original = CaseFormatter::from(input);
+            let words = CaseFormatter::from(input).into_words();
+            assert_eq!(words, original.words());
+
+            let rebuilt = CaseFormatter::from_words(words);
+            for case in [
+                Case::Camel,
+                Case::Pascal,
+                Case::Snake,
+                Case::ScreamingSnake,
+                Case::Kebab,
+                Case::ScreamingKebab,
+                Case::Dot,
+                Case::Title,
+                Case::Lower,
+                Case::Upper,
+            ] {
+                assert_eq!(rebuilt.convert(case), original.convert(case));
+            }
+        }
+    }
+
+    #[test]
+    fn test_equality() {
+        assert!(CaseFormatter::from("brewCoffee") == CaseFormatter::from("brew_coffee"));
+        assert!(CaseFormatter::from("BrewCoffee") == CaseFormatter::from("BREW-COFFEE"));
+        assert!(CaseFormatter::from("brewCoffee") != CaseFormatter::from("brew_tea"));
+
+        let identifiers = ["userName", "user_name", "UserName", "USER-NAME", "userId"];
+        let unique: HashSet<CaseFormatter> = identifiers
+            .iter()
+            .map(|&i| CaseFormatter::from(i))
+            .collect();
+        assert_eq!(unique.len(), 2);
+
+        // Only the words count, not the output options
+        let config = SplitConfig {
+            preserve_underscores: true,
+            ..Default::default()
+        };
+        let preserved = CaseFormatter::from_custom("__brew_coffee__", &config);
+        let plain = CaseFormatter::from("brew_coffee");
+        assert_eq!(preserved.to_snake_case(), "__brew_coffee__");
+        assert!(preserved == plain);
+        let unique: HashSet<CaseFormatter> = [preserved, plain].into_iter().collect();
+        assert_eq!(unique.len(), 1);
+    }
+
+    #[test]
+    fn test_starts_ends_with_word() {
+        let processor = CaseFormatter::from("getUserService");
+        assert!(processor.starts_with_word("get"));
+        assert!(processor.starts_with_word("Get"));
+        assert!(processor.ends_with_word("SERVICE"));
+        assert!(!processor.starts_with_word("user"));
+        assert!(!processor.ends_with_word("user"));
+        assert!(!processor.ends_with_word("ice"));
+
+        let empty = CaseFormatter::from("!!!");
+        assert!(!empty.starts_with_word(""));
+        assert!(!empty.ends_with_word("get"));
+    }
+
+    #[test]
+    fn test_strip_affixes() {
+        let config = SplitConfig::default()
+            .strip_prefixes(&["m_", "s_", "g_", "str", "I", "IFoo"])
+            .strip_suffixes(&["_t", "_impl", "Impl"]);
+
+        let test_cases = vec![
+            ("m_userName", "user_name"),
+            ("s_instanceCount", "instance_count"),
+            ("g_config", "config"),
+            ("strFileName", "file_name"),
+            ("string", "string"),
+            ("IUserService", "user_service"),
+            ("Item", "item"),
+            ("IFooBar", "bar"),
+            ("size_t", "size"),
+            ("parser_impl", "parser"),
+            ("ParserImpl", "parser"),
+            ("Simpl", "simpl"),
+            ("m_", "m"),
+            ("I", "i"),
+            ("m_t", "t"),
+            ("IServiceImpl", "service"),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                CaseFormatter::from_custom(input, &config).to_snake_case(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        // Unicode whitespace separates affixes like any other separator
+        let config = SplitConfig::default()
+            .strip_prefixes(&["pre\u{3000}"])
+            .strip_suffixes(&["\u{2003}post"]);
+        assert_eq!(
+            CaseFormatter::from_custom("pre\u{3000}fix_x\u{2003}post", &config).to_snake_case(),
+            "fix_x"
+        );
+    }
+
+    #[test]
+    fn test_clone_and_debug() {
+        let processor = CaseFormatter::from("brewCoffee");
+        let cloned = processor.clone();
+
+        for case in [Case::Camel, Case::Snake, Case::Title] {
+            assert_eq!(processor.convert(case), cloned.convert(case));
+        }
+        assert!(format!("{:?}", processor).contains(r#"["brew", "coffee"]"#));
+    }
+
+    #[test]
+    fn test_contains_word() {
+        let processor = CaseFormatter::from("userProfileService");
+        assert!(processor.contains_word("user"));
+        assert!(processor.contains_word("PROFILE"));
+        assert!(processor.contains_word("service"));
+        assert!(!processor.contains_word("pro"));
+        assert!(!processor.contains_word("userprofile"));
+
+        assert!(processor.contains_word_at(0, "User"));
+        assert!(processor.contains_word_at(2, "service"));
+        assert!(!processor.contains_word_at(1, "service"));
+        assert!(!processor.contains_word_at(10, "service"));
+    }
+
+    #[test]
+    fn test_accessors() {
+        let test_cases = vec![
+            ("user_name", "getUserName", "setUserName", "isUserName"),
+            ("userName", "getUserName", "setUserName", "isUserName"),
+            ("name", "getName", "setName", "isName"),
+        ];
+
+        for (input, getter, setter, predicate) in test_cases {
+            let processor = CaseFormatter::from(input);
+            assert_eq!(processor.to_getter(), getter);
+            assert_eq!(processor.to_setter(), setter);
+            assert_eq!(processor.to_accessor("is", Case::Camel), predicate);
+        }
+
+        let processor = CaseFormatter::from("user_name");
+        assert_eq!(processor.to_accessor("with", Case::Pascal), "WithUserName");
+        assert_eq!(processor.to_accessor("get", Case::Snake), "get_user_name");
+    }
+
+    #[test]
+    fn test_normalize() {
+        let mut processor = CaseFormatter::from("user");
+        processor.push_word("myNewWord");
+        assert_eq!(processor.words(), ["user", "myNewWord"]);
+        processor.normalize();
+        assert_eq!(processor.words(), ["user", "my", "new", "word"]);
+
+        let mut processor = CaseFormatter::from_words(vec![
+            "HTTP-Server".to_string(),
+            "  ".to_string(),
+            "config_file".to_string(),
+        ]);
+        processor.normalize();
+        assert_eq!(processor.words(), ["http", "server", "config", "file"]);
+
+        // Normalizing an already consistent formatter changes nothing
+        let mut processor = CaseFormatter::from("brewCoffee");
+        processor.normalize();
+        assert_eq!(processor, CaseFormatter::from("brewCoffee"));
+    }
+
+    #[test]
+    fn test_degenerate_inputs() {
+        let processor = CaseFormatter::from("123");
+        assert!(!processor.is_empty());
+        assert_eq!(processor.words(), ["123"]);
+        assert_eq!(processor.to_pascal_case(), "123");
+        assert_eq!(processor.to_camel_case(), "123");
+        assert_eq!(processor.to_snake_case(), "123");
+        assert_eq!(processor.to_screaming_snake_case(), "123");
+
+        for input in ["", "!!!", "@#$%^&*", "   ", "\t\n", "_-. ,"] {
+            let processor = CaseFormatter::from(input);
+            assert!(processor.is_empty(), "Failed for input: '{}'", input);
+            for case in [
+                Case::Camel,
+                Case::Pascal,
+                Case::Snake,
+                Case::ScreamingSnake,
+                Case::Kebab,
+                Case::ScreamingKebab,
+                Case::Dot,
+                Case::Title,
+                Case::Lower,
+                Case::Upper,
+            ] {
+                assert_eq!(processor.convert(case), "", "Failed for input: '{}'", input);
+            }
+        }
+    }
+
+    #[test]
+    fn test_format_template() {
+        let processor = CaseFormatter::from("user_profile");
+
+        assert_eq!(
+            processor
+                .format_template("{pascal}Dto -> {snake}.rs ({kebab}, {dot}, {flat})")
+                .unwrap(),
+            "UserProfileDto -> user_profile.rs (user-profile, user.profile, userprofile)"
+        );
+        assert_eq!(
+            processor.format_template("{camel}: {title}").unwrap(),
+            "userProfile: User Profile"
+        );
+        assert_eq!(
+            processor.format_template("TBL_{SCREAMING}").unwrap(),
+            "TBL_USER_PROFILE"
+        );
+
+        // Escaped braces
+        assert_eq!(
+            processor.format_template("{{{snake}}} {{}}").unwrap(),
+            "{user_profile} {}"
+        );
+
+        // Errors
+        let err = processor.format_template("{pascal}{shouty}").unwrap_err();
+        assert_eq!(err, TemplateError::UnknownPlaceholder("shouty".to_string()));
+        assert!(err.to_string().contains("shouty"));
+        assert_eq!(
+            processor.format_template("ab{snake"),
+            Err(TemplateError::UnclosedPlaceholder { position: 2 })
+        );
+        assert_eq!(
+            processor.format_template("a}b"),
+            Err(TemplateError::UnmatchedBrace { position: 1 })
+        );
+    }
+
+    #[test]
+    fn test_title_case_with_stop_words() {
+        let stop_words = ["the", "a", "of", "in"];
+        let test_cases = vec![
+            ("the quick brown fox", "The Quick Brown Fox"),
+            ("lord_of_the_rings", "Lord of the Rings"),
+            ("aTaleOfTwoCities", "A Tale of Two Cities"),
+            ("what_is_it_in", "What Is It In"),
+            ("of", "Of"),
+            ("", ""),
+        ];
+
+        for (input, expected) in test_cases {
+            let processor = CaseFormatter::from(input);
+            assert_eq!(
+                processor.to_title_case_with_stop_words(&stop_words),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let processor = CaseFormatter::from("walking_through_the_door");
+        assert_eq!(
+            processor.to_title_case_with_stop_words(StopWordList::chicago().words()),
+            "Walking through the Door"
+        );
+        assert_eq!(
+            processor.to_title_case_with_stop_words(StopWordList::apa().words()),
+            "Walking Through the Door"
+        );
+        assert_eq!(
+            processor.to_title_case_with_stop_words(&[]),
+            processor.to_title_case()
+        );
+    }
+
+    #[test]
+    fn test_merge_single_letters() {
+        let config = SplitConfig {
+            merge_single_letters: true,
+            ..Default::default()
+        };
+        let test_cases = vec![
+            ("e-mail", "email"),
+            ("x-ray", "xray"),
+            ("t-shirt sizes", "tshirt_sizes"),
+            ("o'clock", "oclock"),
+            ("u-s-army", "usarmy"),
+            ("aValue", "a_value"),
+            ("get_aValue", "get_a_value"),
+            ("vitamin-c", "vitamin_c"),
+            ("b", "b"),
+            ("v-2", "v2"),
+        ];
+
+        for (input, expected) in test_cases {
+            let processor = CaseFormatter::from_custom(input, &config);
+            assert_eq!(
+                processor.to_snake_case(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        // Disabled by default
+        let processor = CaseFormatter::from_custom("e-mail", &SplitConfig::default());
+        assert_eq!(processor.to_snake_case(), "e_mail");
+    }
+
+    #[test]
+    fn test_sort_words() {
+        let inputs = [
+            "readOnlyHidden",
+            "hidden_read_only",
+            "OnlyHiddenRead",
+            "only-read-hidden",
+        ];
+        for input in inputs {
+            let processor = CaseFormatter::from(input);
+            assert_eq!(
+                processor.sort_words().to_snake_case(),
+                "hidden_only_read",
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        // Sorting leaves the original untouched
+        let processor = CaseFormatter::from("zetaAlpha");
+        let sorted = processor.sort_words();
+        assert_eq!(processor.words(), ["zeta", "alpha"]);
+        assert_eq!(sorted.words(), ["alpha", "zeta"]);
+
+        let reversed = processor.sort_words_by(|a, b| b.cmp(a));
+        assert_eq!(reversed.to_pascal_case(), "ZetaAlpha");
+    }
+
+    #[test]
+    fn test_unicode_whitespace_separators() {
+        let test_cases = vec![
+            ("brew\u{00A0}coffee", "brew_coffee"),
+            ("brew\u{2009}coffee", "brew_coffee"),
+            ("brew\u{3000}hot\u{00A0}\u{2009}coffee", "brew_hot_coffee"),
+            ("brew\tcoffee\nnow", "brew_coffee_now"),
+            ("\u{00A0}brewCoffee\u{2009}", "brew_coffee"),
+        ];
+
+        for (input, expected) in test_cases {
+            let processor = CaseFormatter::from(input);
+            assert_eq!(
+                processor.to_snake_case(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let processor = CaseFormatter::from_ascii_bytes(b"brew\tcoffee").unwrap();
+        assert_eq!(processor.to_snake_case(), "brew_coffee");
+    }
+
+    #[test]
+    fn test_title_case_all_caps_tokens() {
+        let options = TitleCaseOptions::default();
+        let test_cases = vec![
+            ("world_war_ii", "World War II"),
+            ("ii_the_sequel", "II The Sequel"),
+            ("henry_v_part_iv", "Henry V Part IV"),
+            ("chapter_xxxix", "Chapter XXXIX"),
+            ("chapter_xl", "Chapter Xl"),
+            ("mix_and_liv", "Mix And Liv"),
+            ("iiii_ixi_vv", "Iiii Ixi Vv"),
+            ("nasa_launch", "Nasa Launch"),
+        ];
+
+        for (input, expected) in test_cases {
+            let processor = CaseFormatter::from(input);
+            assert_eq!(
+                processor.to_title_case_custom(&options),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        // Acronyms and a raised bound
+        let options = TitleCaseOptions {
+            acronyms: vec!["NASA".to_string()],
+            max_roman_numeral: 2000,
+            ..Default::default()
+        };
+        let processor = CaseFormatter::from("nasa_mix_launch");
+        assert_eq!(processor.to_title_case_custom(&options), "NASA MIX Launch");
+
+        // Interplay with stop words
+        let options = TitleCaseOptions {
+            stop_words: vec!["of".to_string(), "the".to_string(), "i".to_string()],
+            ..Default::default()
+        };
+        let processor = CaseFormatter::from("war_of_the_worlds_i_ii");
+        assert_eq!(
+            processor.to_title_case_custom(&options),
+            "War of the Worlds I II"
+        );
+
+        // Disabled, and never applied to other styles
+        let options = TitleCaseOptions {
+            preserve_all_caps_tokens: false,
+            ..Default::default()
+        };
+        let processor = CaseFormatter::from("world_war_ii");
+        assert_eq!(processor.to_title_case_custom(&options), "World War Ii");
+        assert_eq!(processor.to_title_case(), "World War Ii");
+        assert_eq!(processor.to_pascal_case(), "WorldWarIi");
+        assert_eq!(processor.to_snake_case(), "world_war_ii");
+    }
+
+    #[test]
+    fn test_reversed() {
+        let processor = CaseFormatter::from("com.example.app");
+        assert_eq!(processor.reversed().to_dot_case(), "app.example.com");
+        assert_eq!(processor.reversed().to_pascal_case(), "AppExampleCom");
+        assert_eq!(processor.reversed().reversed(), processor);
+        assert_eq!(processor.words(), ["com", "example", "app"]);
+
+        let processor = CaseFormatter::from("");
+        assert!(processor.reversed().is_empty());
+    }
+
+    #[test]
+    fn test_name_case() {
+        let config = SplitConfig::person_name();
+        let test_cases = vec![
+            (
+                "MCDONALD o'brien van der berg",
+                "McDonald O'Brien van der Berg",
+            ),
+            ("john smith", "John Smith"),
+            ("JANE DOE", "Jane Doe"),
+            ("mcdonald", "McDonald"),
+            ("McDonald", "McDonald"),
+            ("macleod", "MacLeod"),
+            ("MACDONALD", "MacDonald"),
+            ("macy", "Macy"),
+            ("mack", "Mack"),
+            ("machado", "Machado"),
+            ("o'brien", "O'Brien"),
+            ("d'angelo", "D'Angelo"),
+            ("o’neill", "O'Neill"),
+            ("smith-jones", "Smith-Jones"),
+            ("mary-kate o'connor-mcbride", "Mary-Kate O'Connor-McBride"),
+            ("ludwig van beethoven", "Ludwig van Beethoven"),
+            ("van gogh", "Van Gogh"),
+            ("leonardo da vinci", "Leonardo da Vinci"),
+            ("otto VON bismarck", "Otto von Bismarck"),
+            ("charles de gaulle", "Charles de Gaulle"),
+            ("  anna   karenina ", "Anna Karenina"),
+            ("jean-luc picard", "Jean-Luc Picard"),
+            ("", ""),
+        ];
+
+        for (input, expected) in test_cases {
+            let processor = CaseFormatter::from_custom(input, &config);
+            assert_eq!(
+                processor.to_name_case(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        // Configurable particles and Mc/Mac heuristic
+        let options = NameCaseOptions {
+            particles: vec!["bin".to_string()],
+            mc_mac_prefixes: false,
+            ..Default::default()
+        };
+        let processor = CaseFormatter::from_custom("macdonald bin van", &config);
+        assert_eq!(processor.to_name_case_custom(&options), "Macdonald bin Van");
+
+        // The default splitter drops apostrophes and splits hyphens
+        let processor = CaseFormatter::from("o'brien smith-jones");
+        assert_eq!(processor.to_name_case(), "Obrien Smith Jones");
+    }
+
+    #[test]
+    fn test_split_punctuation_options() {
+        let config = SplitConfig {
+            preserve_apostrophes: true,
+            ..Default::default()
+        };
+        let processor = CaseFormatter::from_custom("don't 'quote' it'", &config);
+        assert_eq!(processor.words(), ["don't", "quote", "it"]);
+
+        let config = SplitConfig {
+            preserve_hyphens: true,
+            ..Default::default()
+        };
+        let processor = CaseFormatter::from_custom("-smith-jones- x--y", &config);
+        assert_eq!(processor.words(), ["smith-jones", "x-y"]);
+
+        let config = SplitConfig {
+            ignore_case_boundaries: true,
+            ..Default::default()
+        };
+        let processor = CaseFormatter::from_custom("McDonald_iPhone", &config);
+        assert_eq!(processor.words(), ["mcdonald", "iphone"]);
+    }
+
+    #[test]
+    fn test_abbreviations() {
+        let config = SplitConfig::default().expand_abbreviations(&[
+            ("cfg", "config"),
+            ("num", "number"),
+            ("addr", "address"),
+            ("qty", "quantity"),
+            ("pk", "primaryKey"),
+        ]);
+        let test_cases = vec![
+            ("cfgMaxNum", "config_max_number", "ConfigMaxNumber"),
+            ("CFG_ADDR", "config_address", "ConfigAddress"),
+            ("order_qty", "order_quantity", "OrderQuantity"),
+            ("user_pk", "user_primary_key", "UserPrimaryKey"),
+            ("numeric_cfgs", "numeric_cfgs", "NumericCfgs"),
+        ];
+
+        for (input, snake, pascal) in test_cases {
+            let processor = CaseFormatter::from_custom(input, &config);
+            assert_eq!(
+                processor.to_snake_case(),
+                snake,
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                processor.to_pascal_case(),
+                pascal,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        // Expanded words are not expanded again
+        let config = SplitConfig::default().expand_abbreviations(&[("a", "a b"), ("b", "c")]);
+        let processor = CaseFormatter::from_custom("a_b", &config);
+        assert_eq!(processor.to_snake_case(), "a_b_c");
+
+        // Chained with acronym handling
+        let config = SplitConfig::default().expand_abbreviations(&[("nasa", "nasa api")]);
+        let options = TitleCaseOptions {
+            acronyms: vec!["nasa".to_string(), "api".to_string()],
+            ..Default::default()
+        };
+        let processor = CaseFormatter::from_custom("nasaClient", &config);
+        assert_eq!(processor.to_title_case_custom(&options), "NASA API Client");
+
+        // Abbreviating goes the other way
+        let config =
+            SplitConfig::default().abbreviate_words(&[("config", "cfg"), ("number", "num")]);
+        let processor = CaseFormatter::from_custom("ConfigMaxNumber", &config);
+        assert_eq!(processor.to_snake_case(), "cfg_max_num");
+    }
+
+    #[test]
+    fn test_with_prefix_suffix() {
+        let processor = CaseFormatter::from("coffee");
+        assert_eq!(processor.with_prefix("brew").to_camel_case(), "brewCoffee");
+        assert_eq!(processor.with_suffix("Impl").to_pascal_case(), "CoffeeImpl");
+        assert_eq!(
+            processor
+                .with_prefix("get")
+                .with_suffix("by_id")
+                .to_camel_case(),
+            "getCoffeeById"
+        );
+
+        // Added words are tokenized
+        let processor = CaseFormatter::from("user_name");
+        assert_eq!(
+            processor.with_prefix("IsValid").words(),
+            ["is", "valid", "user", "name"]
+        );
+        assert_eq!(
+            processor.with_suffix("for-HTTP request").to_snake_case(),
+            "user_name_for_http_request"
+        );
+
+        // Empty and symbol-only additions change nothing
+        assert_eq!(processor.with_prefix(""), processor);
+        assert_eq!(processor.with_suffix("!!"), processor);
+        assert_eq!(processor.words(), ["user", "name"]);
+    }
+
+    #[test]
+    fn test_digits_in_words() {
+        // (input, default pascal, split_after_digits pascal, numbers attached pascal)
+        let test_cases = vec![
+            ("utf8_string", "Utf8String", "Utf8String", "Utf8string"),
+            (
+                "base64_encode",
+                "Base64Encode",
+                "Base64Encode",
+                "Base64encode",
+            ),
+            ("mp3_player", "Mp3Player", "Mp3Player", "Mp3player"),
+            ("mp3player", "Mp3player", "Mp3Player", "Mp3player"),
+            (
+                "http2Request",
+                "Http2request",
+                "Http2Request",
+                "Http2request",
+            ),
+            ("sha256", "Sha256", "Sha256", "Sha256"),
+            ("3dModel", "3DModel", "3DModel", "3DModel"),
+        ];
+
+        let split_digits = SplitConfig {
+            split_after_digits: true,
+            ..Default::default()
+        };
+        for (input, pascal, split_pascal, attached) in test_cases {
+            let processor = CaseFormatter::from(input);
+            assert_eq!(
+                processor.to_pascal_case(),
+                pascal,
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                processor.to_pascal_case_with_numbers_attached(),
+                attached,
+                "Failed for input: '{}'",
+                input
+            );
+
+            let processor = CaseFormatter::from_custom(input, &split_digits);
+            assert_eq!(
+                processor.to_pascal_case(),
+                split_pascal,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let processor = CaseFormatter::from_custom("mp3player", &split_digits);
+        assert_eq!(processor.words(), ["mp3", "player"]);
+        assert_eq!(processor.to_snake_case(), "mp3_player");
+    }
+
+    #[test]
+    fn test_numeric_grouping() {
+        let test_cases = vec![
+            ("2023_04_01", "2023_04_01", "2023_04_01", true),
+            (
+                "release_2023_04_01",
+                "release2023_04_01",
+                "Release2023_04_01",
+                false,
+            ),
+            ("v1_2_3", "v1_2_3", "V1_2_3", true),
+            ("version_1_2_3", "version1_2_3", "Version1_2_3", false),
+            ("192.168.0.1", "192_168_0_1", "192_168_0_1", true),
+            ("ipv4_10_0_0_1", "ipv4_10_0_0_1", "Ipv4_10_0_0_1", true),
+            ("page_2_of_10", "page2Of10", "Page2Of10", false),
+        ];
+
+        for (input, expected_camel, expected_pascal, round_trips) in test_cases {
+            let processor = CaseFormatter::from(input);
+            let camel = processor.to_camel_case();
+            assert_eq!(camel, expected_camel, "Failed for input: '{}'", input);
+            assert_eq!(
+                processor.to_pascal_case(),
+                expected_pascal,
+                "Failed for input: '{}'",
+                input
+            );
+
+            // Numeric groups survive a round trip unless a word glues onto a number
+            if round_trips {
+                assert_eq!(
+                    CaseFormatter::from(camel).words(),
+                    processor.words(),
+                    "Failed for input: '{}'",
+                    input
+                );
+            }
+        }
+
+        let processor = CaseFormatter::from("2023_04_01");
+        assert_eq!(processor.format_template("{flat}").unwrap(), "2023_04_01");
+
+        // A custom separator, or none at all
+        let dashed = SplitConfig {
+            numeric_separator: Some("x".to_string()),
+            ..Default::default()
+        };
+        let processor = CaseFormatter::from_custom("v1_2_3", &dashed);
+        assert_eq!(processor.to_pascal_case(), "V1x2x3");
+
+        let joined = SplitConfig {
+            numeric_separator: Some(String::new()),
+            ..Default::default()
+        };
+        let processor = CaseFormatter::from_custom("2023_04_01", &joined);
+        assert_eq!(processor.to_camel_case(), "20230401");
+        assert_eq!(processor.to_snake_case(), "2023_04_01");
+    }
+
+    #[test]
+    fn test_from_utf8() {
+        // Valid UTF-8 behaves like the equivalent &str
+        for input in ["brewCoffee", "brew_coffee", "Crème Brûlée", ""] {
+            assert_eq!(
+                CaseFormatter::from_utf8(input.as_bytes()).unwrap(),
+                CaseFormatter::from(input),
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        // Truncated multi-byte sequence
+        let truncated = &"brew_café".as_bytes()[..9];
+        assert_eq!(
+            CaseFormatter::from_utf8(truncated),
+            Err(Utf8CaseError::InvalidSequence { position: 8 })
+        );
+        assert_eq!(
+            CaseFormatter::from_utf8_lossy(truncated).to_snake_case(),
+            "brew_caf"
+        );
+        assert_eq!(
+            Utf8CaseError::InvalidSequence { position: 8 }.to_string(),
+            "Invalid UTF-8 sequence at offset 8"
+        );
+
+        // Interior NUL is valid UTF-8 and dropped without a word boundary
+        let with_nul = b"brew\0coffee_now";
+        assert_eq!(
+            CaseFormatter::from_utf8(with_nul).unwrap().to_snake_case(),
+            "brewcoffee_now"
+        );
+        assert_eq!(
+            CaseFormatter::from_utf8_lossy(b"brew\xff\xfe_coffee").to_snake_case(),
+            "brew_coffee"
+        );
+    }
+
+    #[test]
+    fn test_from_csv() {
+        let test_cases = vec![
+            ("Brew , Coffee , Machine", vec!["brew", "coffee", "machine"]),
+            ("brew,coffee", vec!["brew", "coffee"]),
+            ("  brew,\tcoffee  ,", vec!["brew", "coffee"]),
+            ("brew,,, coffee", vec!["brew", "coffee"]),
+            ("userName, HTTP", vec!["user", "name", "http"]),
+            (" , ", vec![]),
+            ("", vec![]),
+        ];
+
+        for (input, expected) in test_cases {
+            let processor = CaseFormatter::from_csv(input);
+            assert_eq!(processor.words(), expected, "Failed for input: '{}'", input);
+        }
+
+        assert_eq!(
+            CaseFormatter::from_csv("Brew , Coffee").to_snake_case(),
+            "brew_coffee"
+        );
+    }
+
+    #[test]
+    fn test_convert_with() {
+        let capitalize = |word: &str| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        };
+        let corpus = [
+            "brew_coffee",
+            "brewCoffee",
+            "BrewCoffee",
+            "brew-coffee",
+            "BREW_COFFEE",
+            "brew.coffee",
+            "Brew Coffee",
+            "XMLHttpRequest",
+            "getHTTPResponse",
+            "a",
+            "",
+            "  brew   the coffee ",
+        ];
+
+        for input in corpus {
+            let processor = CaseFormatter::from(input);
+            let camel = processor.convert_with("", |i, w| {
+                if i == 0 {
+                    w.to_lowercase()
+                } else {
+                    capitalize(w)
+                }
+            });
+            assert_eq!(
+                camel,
+                processor.to_camel_case(),
+                "Failed for input: '{}'",
+                input
+            );
+
+            let snake = processor.convert_with("_", |_, w| w.to_string());
+            assert_eq!(
+                snake,
+                processor.to_snake_case(),
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let processor = CaseFormatter::from("brew_hot_coffee_now");
+        let every_second = processor.convert_with("-", |i, w| {
+            if i % 2 == 1 {
+                capitalize(w)
+            } else {
+                w.to_string()
+            }
+        });
+        assert_eq!(every_second, "brew-Hot-coffee-Now");
+    }
+
+    #[test]
+    fn test_into_string_with() {
+        let test_cases = vec![
+            ("/", CapitalizationMode::None, "brew/hot/coffee"),
+            ("/", CapitalizationMode::FirstWord, "Brew/hot/coffee"),
+            ("/", CapitalizationMode::AllWords, "Brew/Hot/Coffee"),
+            (" ", CapitalizationMode::FirstWord, "Brew hot coffee"),
+            ("", CapitalizationMode::AllWords, "BrewHotCoffee"),
+            ("::", CapitalizationMode::None, "brew::hot::coffee"),
+        ];
+
+        for (separator, cap, expected) in test_cases {
+            let processor = CaseFormatter::from("brewHot_coffee");
+            assert_eq!(processor.into_string_with(separator, cap), expected);
+        }
+
+        let processor = CaseFormatter::from("");
+        assert_eq!(
+            processor.into_string_with("/", CapitalizationMode::AllWords),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_convert_embedded() {
+        let test_cases = vec![
+            ("fooBar()", Case::Snake, "foo_bar()"),
+            ("(fooBar)", Case::Pascal, "(FooBar)"),
+            ("\"userName\"", Case::Snake, "\"user_name\""),
+            ("'user_name'", Case::Camel, "'userName'"),
+            ("userId,", Case::ScreamingSnake, "USER_ID,"),
+            ("user_id;", Case::Kebab, "user-id;"),
+            ("&self.userId);", Case::Snake, "&self_user_id);"),
+            ("_privateField,", Case::Snake, "_private_field,"),
+            ("fooBar", Case::Snake, "foo_bar"),
+            ("();,", Case::Snake, "();,"),
+            ("", Case::Snake, ""),
+        ];
+
+        for (input, case, expected) in test_cases {
+            assert_eq!(
+                convert_embedded(input, case),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_path_case() {
+        let test_cases = vec![
+            ("brew.coffee.now", "brew/coffee/now"),
+            ("BrewCoffeeNow", "brew/coffee/now"),
+            ("brew_coffee", "brew/coffee"),
+            ("brew", "brew"),
+            ("", ""),
+            // `/` is not a separator and is dropped without splitting
+            ("brew/coffee", "brewcoffee"),
+            ("brew/hot_coffee", "brewhot/coffee"),
+        ];
+
+        for (input, expected) in test_cases {
+            let processor = CaseFormatter::from(input);
+            assert_eq!(
+                processor.to_path_case(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let nested: Vec<String> = "brewCoffee/hotMilk"
+            .split('/')
+            .map(|segment| CaseFormatter::from(segment).to_snake_case())
+            .collect();
+        assert_eq!(nested.join("/"), "brew_coffee/hot_milk");
+    }
+
+    #[test]
+    fn test_natural_order() {
+        use std::cmp::Ordering;
+
+        let test_cases = vec![
+            ("item2", "item10", Ordering::Less),
+            ("item_2", "item_10", Ordering::Less),
+            ("item10", "Item_2", Ordering::Greater),
+            ("item002", "item2", Ordering::Less),
+            ("item002", "item3", Ordering::Less),
+            ("item2", "ITEM_2", Ordering::Equal),
+            ("v1beta2", "v1beta10", Ordering::Less),
+            ("v1beta2", "v1alpha10", Ordering::Greater),
+            ("v2beta1", "v10alpha1", Ordering::Less),
+            ("item", "item_2", Ordering::Less),
+            (
+                "item99999999999999999999999",
+                "item100000000000000000000000",
+                Ordering::Less,
+            ),
+        ];
+
+        for (a, b, expected) in test_cases {
+            assert_eq!(
+                cmp_natural_ignore_case_style(a, b),
+                expected,
+                "Failed for input: '{}'",
+                a
+            );
+            assert_eq!(
+                cmp_natural_ignore_case_style(b, a),
+                expected.reverse(),
+                "Failed for input: '{}'",
+                b
+            );
+        }
+
+        let processor = CaseFormatter::from("item10_item2_item002_item1");
+        assert_eq!(
+            processor.sort_words_in(WordOrder::Natural).to_snake_case(),
+            "item1_item002_item2_item10"
+        );
+        assert_eq!(
+            processor.sort_words_in(WordOrder::Lexicographic),
+            processor.sort_words()
+        );
+    }
+
+    #[test]
+    fn test_try_from_strict() {
+        let accepted = [
+            ("brew_coffee", vec!["brew", "coffee"]),
+            ("BrewCoffee", vec!["brew", "coffee"]),
+            (
+                "brew-coffee.v2, final",
+                vec!["brew", "coffee", "v2", "final"],
+            ),
+            ("brew\tcoffee", vec!["brew", "coffee"]),
+            ("", vec![]),
+        ];
+        for (input, expected) in accepted {
+            assert_eq!(
+                CaseFormatter::try_from_strict(input).unwrap().words(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let rejected = [
+            ("user;DROP TABLE", 4, ';'),
+            ("brew&coffee", 4, '&'),
+            ("don't", 3, '\''),
+            ("brew/coffee", 4, '/'),
+            ("café", 3, 'é'),
+            ("brew\0coffee", 4, '\0'),
+            ("brew☕", 4, '☕'),
+        ];
+        for (input, position, character) in rejected {
+            assert_eq!(
+                CaseFormatter::try_from_strict(input).err(),
+                Some(CaseParseError {
+                    position,
+                    character
+                }),
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        assert_eq!(
+            CaseParseError {
+                position: 4,
+                character: ';'
+            }
+            .to_string(),
+            "Unsupported character ';' at offset 4"
+        );
+    }
+
+    #[test]
+    fn test_merge_operators() {
+        let brew = CaseFormatter::from("brew");
+        let coffee = CaseFormatter::from("hotCoffee");
+        let machine = CaseFormatter::from("machine");
+
+        assert_eq!(brew.merge(&coffee).words(), ["brew", "hot", "coffee"]);
+        assert_eq!((brew.clone() + &coffee).words(), ["brew", "hot", "coffee"]);
+
+        // `+` is left-associative, chaining keeps the written order
+        let chained = brew.clone() + &coffee + &machine;
+        assert_eq!(chained.words(), ["brew", "hot", "coffee", "machine"]);
+        assert_eq!(chained, brew.clone() + &(coffee.clone() + &machine));
+        assert_eq!(chained.to_pascal_case(), "BrewHotCoffeeMachine");
+
+        let mut assigned = brew.clone();
+        assigned += &coffee;
+        assigned += &machine;
+        assert_eq!(assigned, chained);
+
+        assert_eq!(brew.clone() + &CaseFormatter::from(""), brew);
+        assert_eq!(CaseFormatter::from("") + &brew, brew);
+
+        let config = SplitConfig {
+            preserve_underscores: true,
+            ..Default::default()
+        };
+        let private = CaseFormatter::from_custom("_brew", &config);
+        let dunder = CaseFormatter::from_custom("coffee__", &config);
+        assert_eq!((private + &dunder).to_snake_case(), "_brew_coffee__");
+    }
+
+    #[test]
+    fn test_strict_case_formatter() {
+        let accepted = [
+            ("brewCoffee", "brew_coffee"),
+            ("  brew-coffee  ", "brew_coffee"),
+            ("HTTP2", "http2"),
+        ];
+        for (input, expected) in accepted {
+            let processor = StrictCaseFormatter::try_from(input).unwrap();
+            assert_eq!(
+                processor.to_snake_case(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let rejected = [
+            ("", StrictCaseError::NoWords),
+            ("_-. ,", StrictCaseError::NoWords),
+            (
+                "brew;coffee",
+                StrictCaseError::UnsupportedCharacter(CaseParseError {
+                    position: 4,
+                    character: ';',
+                }),
+            ),
+            (
+                "!!!",
+                StrictCaseError::UnsupportedCharacter(CaseParseError {
+                    position: 0,
+                    character: '!',
+                }),
+            ),
+        ];
+        for (input, expected) in rejected {
+            assert_eq!(
+                StrictCaseFormatter::try_from(input).err(),
+                Some(expected),
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let owned = String::from("brew_coffee");
+        assert_eq!(
+            StrictCaseFormatter::try_from(&owned).unwrap(),
+            StrictCaseFormatter::try_from(owned).unwrap()
+        );
+        assert_eq!(
+            CaseFormatter::from(StrictCaseFormatter::try_from("brewCoffee").unwrap()),
+            CaseFormatter::from("brew_coffee")
+        );
+        assert_eq!(
+            StrictCaseError::NoWords.to_string(),
+            "Input contains no words"
+        );
+    }
+
+    #[test]
+    fn test_word_access() {
+        let processor = CaseFormatter::from("brewCoffee_machine");
+
+        assert_eq!(&processor[0], "brew");
+        assert_eq!(&processor[2], "machine");
+        assert_eq!(processor.word_at(1), Some("coffee"));
+        assert_eq!(processor.word_at(3), None);
+        assert_eq!(CaseFormatter::from("").word_at(0), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let processor = CaseFormatter::from("brewCoffee");
+        let _ = &processor[2];
+    }
+
+    #[test]
+    fn test_format_with_case_spec() {
+        let inputs = [
+            "brew_coffee",
+            "BrewCoffeeMachine",
+            "123abc_def",
+            "version_1_2",
+            "XMLHttpRequest",
+            "a",
+            "",
+        ];
+        let specs = [
+            (
+                CaseSpec::new("", WordCase::Lower, WordCase::Capitalize),
+                Case::Camel,
+            ),
+            (
+                CaseSpec::new("", WordCase::Capitalize, WordCase::Capitalize),
+                Case::Pascal,
+            ),
+            (
+                CaseSpec::new("_", WordCase::Lower, WordCase::Lower),
+                Case::Snake,
+            ),
+            (
+                CaseSpec::new("_", WordCase::Upper, WordCase::Upper),
+                Case::ScreamingSnake,
+            ),
+            (
+                CaseSpec::new("-", WordCase::Lower, WordCase::Lower),
+                Case::Kebab,
+            ),
+            (
+                CaseSpec::new("-", WordCase::Upper, WordCase::Upper),
+                Case::ScreamingKebab,
+            ),
+            (
+                CaseSpec::new(".", WordCase::Lower, WordCase::Lower),
+                Case::Dot,
+            ),
+            (
+                CaseSpec::new(" ", WordCase::Capitalize, WordCase::Capitalize),
+                Case::Title,
+            ),
+        ];
+
+        for input in inputs {
+            let processor = CaseFormatter::from(input);
+            for (spec, case) in &specs {
+                assert_eq!(spec, &CaseSpec::from(*case));
+                assert_eq!(
+                    processor.format(spec),
+                    processor.convert(*case),
+                    "Failed for input: '{}'",
+                    input
+                );
+            }
+        }
+
+        let processor = CaseFormatter::from("brew_coffee");
+        let expected = [
+            "brewCoffee",
+            "BrewCoffee",
+            "brew_coffee",
+            "BREW_COFFEE",
+            "brew-coffee",
+            "BREW-COFFEE",
+            "brew.coffee",
+            "Brew Coffee",
+        ];
+        for ((spec, _), expected) in specs.iter().zip(expected) {
+            assert_eq!(processor.format(spec), expected);
+        }
+        assert_eq!(
+            processor.format(&CaseSpec::new("::", WordCase::Capitalize, WordCase::Lower)),
+            "Brew::coffee"
+        );
+        assert_eq!(
+            CaseFormatter::from("123abc").format(&CaseSpec::new(
+                "",
+                WordCase::Upper,
+                WordCase::Lower
+            )),
+            "123ABC"
+        );
+
+        // flatcase keeps the letters after leading digits lowercase
+        let flat = CaseSpec::new("", WordCase::Lower, WordCase::Lower);
+        assert_eq!(CaseFormatter::from("123abc").format(&flat), "123abc");
+        assert_eq!(CaseFormatter::from("123abc_def").format(&flat), "123abcdef");
+    }
+
+    #[test]
+    fn test_to_json_key() {
+        let inputs = ["user_name", "UserName", "user-name", "HTTP_status_code"];
+        for input in inputs {
+            let processor = CaseFormatter::from(input);
+            assert_eq!(
+                processor.to_json_key(),
+                processor.to_camel_case(),
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let processor = CaseFormatter::from("HTTP_status_code");
+        let test_cases = [
+            (JsonNamingConvention::Camel, "httpStatusCode"),
+            (JsonNamingConvention::Snake, "http_status_code"),
+            (JsonNamingConvention::Kebab, "http-status-code"),
+            (JsonNamingConvention::Pascal, "HttpStatusCode"),
+        ];
+        for (convention, expected) in test_cases {
+            assert_eq!(
+                processor.to_json_key_with(convention),
+                expected,
+                "Failed for convention: '{:?}'",
+                convention
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_valid_identifier() {
+        let test_cases = [
+            ("user_name", IdentLang::Rust, true),
+            ("userName", IdentLang::Js, true),
+            ("_private", IdentLang::Python, true),
+            ("$scope", IdentLang::Js, true),
+            ("$scope", IdentLang::Rust, false),
+            ("_", IdentLang::Rust, false),
+            ("_", IdentLang::Python, true),
+            ("", IdentLang::Js, false),
+            ("user-name", IdentLang::Python, false),
+            ("match", IdentLang::Rust, false),
+            ("match", IdentLang::Js, true),
+            ("class", IdentLang::Python, false),
+            ("None", IdentLang::Python, false),
+            ("none", IdentLang::Python, true),
+            ("Self", IdentLang::Rust, false),
+            ("function", IdentLang::Js, false),
+            ("größe", IdentLang::Rust, true),
+        ];
+        for (input, lang, expected) in test_cases {
+            assert_eq!(
+                is_valid_identifier(input, lang),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        // Converted names can still be invalid
+        let leading_digit = CaseFormatter::from("3d_model").to_snake_case();
+        assert_eq!(leading_digit, "3d_model");
+        assert!(!is_valid_identifier(&leading_digit, IdentLang::Rust));
+
+        let keyword = CaseFormatter::from("Type").to_snake_case();
+        assert!(!is_valid_identifier(&keyword, IdentLang::Rust));
+        assert!(is_valid_identifier(&keyword, IdentLang::Python));
+    }
+
+    #[test]
+    fn test_split_preserving_case() {
+        let config = SplitConfig::default();
+        let test_cases: Vec<(&str, Vec<&str>)> = vec![
+            ("bRewCofFee", vec!["b", "Rew", "Cof", "Fee"]),
+            ("BREW_coffee", vec!["BREW", "coffee"]),
+            ("iPhone macOS", vec!["i", "Phone", "mac", "OS"]),
+            ("XMLHttpRequest", vec!["XMLHttp", "Request"]),
+            ("", vec![]),
+        ];
+
+        for (input, expected) in test_cases {
+            let cased = str_split_preserving_case(input, &config);
+            assert_eq!(cased, expected, "Failed for input: '{}'", input);
+
+            let lowered: Vec<String> = cased.iter().map(|word| word.to_lowercase()).collect();
+            assert_eq!(
+                str_split_custom(input, &config),
+                lowered,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_sql_identifier() {
+        let test_cases = [
+            ("OrderItems", "order_items", "order_items"),
+            ("userId", "user_id", "user_id"),
+            ("Order", "\"order\"", "order"),
+            ("SELECT", "\"select\"", "select"),
+            ("user", "\"user\"", "user"),
+            ("2fa_codes", "\"2fa_codes\"", "2fa_codes"),
+            ("order_by", "order_by", "order_by"),
+        ];
+
+        for (input, quoted, unquoted) in test_cases {
+            let processor = CaseFormatter::from(input);
+            assert_eq!(
+                processor.to_sql_identifier(true),
+                quoted,
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                processor.to_sql_identifier(false),
+                unquoted,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_split_on_slash() {
+        let config = SplitConfig {
+            split_on_slash: true,
+            ..Default::default()
+        };
+        let test_cases = vec![
+            ("api/user/profile", "api_user_profile"),
+            ("/api/v2/userProfile/", "api_v2_user_profile"),
+            ("https://example.com/getUser", "https_example_com_get_user"),
+            ("src//fmt_path", "src_fmt_path"),
+            ("users/{id}/posts", "users_id_posts"),
+        ];
+
+        for (input, expected) in test_cases {
+            let processor = CaseFormatter::from_custom(input, &config);
+            assert_eq!(
+                processor.to_snake_case(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let processor = CaseFormatter::from_custom("api/user/profile", &config);
+        assert_eq!(processor.to_path_case(), "api/user/profile");
+        assert_eq!(processor.to_camel_case(), "apiUserProfile");
+
+        let merged = SplitConfig {
+            merge_single_letters: true,
+            ..config
+        };
+        let processor = CaseFormatter::from_custom("e-mail/x-ray", &merged);
+        assert_eq!(processor.to_snake_case(), "email_xray");
+
+        // Disabled by default
+        let processor = CaseFormatter::from_custom("api/user/profile", &SplitConfig::default());
+        assert_eq!(processor.to_snake_case(), "apiuserprofile");
+    }
+
+    #[test]
+    fn test_case_convert() {
+        let owned = String::from("brewCoffee");
+        let borrowed = &owned;
+        let test_cases = vec![
+            ("brew_coffee", "brew-coffee"),
+            ("BrewCoffee", "brew-coffee"),
+            ("__brew..coffee__", "brew-coffee"),
+            ("", ""),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                input.to_kebab_case(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                input.to_string().to_kebab_case(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        assert_eq!(owned.to_snake_case(), "brew_coffee");
+        assert_eq!(borrowed.to_pascal_case(), "BrewCoffee");
+        assert_eq!(
+            borrowed.to_case(Case::ScreamingSnake),
+            CaseFormatter::from(borrowed).convert(Case::ScreamingSnake)
+        );
+    }
+
+    #[test]
+    fn test_from_namespace() {
+        let test_cases = vec![
+            ("::cpp::namespace::Type", "cpp_namespace_type"),
+            ("java.style.ClassName", "java_style_class_name"),
+            ("key:value", "key_value"),
+            ("/robot/arm:joint", "robot_arm_joint"),
+            ("std::io::Error", "std_io_error"),
+            ("brew_coffee", "brew_coffee"),
+        ];
+
+        for (input, expected) in test_cases {
+            let processor = CaseFormatter::from_namespace(input);
+            assert_eq!(
+                processor.to_snake_case(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let config = SplitConfig {
+            split_on_colon: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            CaseFormatter::from_custom("a/b:c", &config).to_snake_case(),
+            "ab_c"
+        );
+
+        // Disabled by default
+        assert_eq!(CaseFormatter::from("key:value").to_snake_case(), "keyvalue");
+    }
+
+    #[test]
+    fn test_strip_at_prefix() {
+        let keep = SplitConfig {
+            strip_at_prefix: false,
+            ..Default::default()
+        };
+        let test_cases = vec![
+            ("@Component", "component", "@component", "@Component"),
+            (
+                "@my-decorator",
+                "myDecorator",
+                "@myDecorator",
+                "@MyDecorator",
+            ),
+            ("@@double", "double", "@@double", "@@Double"),
+            ("user@example", "userexample", "userexample", "Userexample"),
+        ];
+
+        for (input, stripped, kept_camel, kept_pascal) in test_cases {
+            assert_eq!(
+                CaseFormatter::from(input).to_camel_case(),
+                stripped,
+                "Failed for input: '{}'",
+                input
+            );
+            let processor = CaseFormatter::from_custom(input, &keep);
+            assert_eq!(
+                processor.to_camel_case(),
+                kept_camel,
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                processor.to_pascal_case(),
+                kept_pascal,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let processor = CaseFormatter::from_custom(
+            "@_private",
+            &SplitConfig {
+                preserve_underscores: true,
+                ..keep
+            },
+        );
+        assert_eq!(processor.to_snake_case(), "@_private");
+        assert!(SplitConfig::default().strip_at_prefix);
+    }
+
+    #[test]
+    fn test_preserve_inner_case() {
+        let config = SplitConfig {
+            preserve_inner_case: true,
+            ..Default::default()
+        };
+        let test_cases = vec![
+            ("bRewCofFee", "bRewCofFee", "BRewCofFee", "b_rew_cof_fee"),
+            ("iPhone", "iPhone", "IPhone", "i_phone"),
+            ("macOS", "macOS", "MacOS", "mac_os"),
+            ("IPhone", "iPhone", "IPhone", "iphone"),
+            (
+                "open_macOS_app",
+                "openMacOSApp",
+                "OpenMacOSApp",
+                "open_mac_os_app",
+            ),
+        ];
+
+        for (input, camel, pascal, snake) in test_cases {
+            let processor = CaseFormatter::from_custom(input, &config);
+            assert_eq!(
+                processor.to_camel_case(),
+                camel,
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                processor.to_pascal_case(),
+                pascal,
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                processor.to_snake_case(),
+                snake,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        // Lowercased by default
+        assert_eq!(CaseFormatter::from("macOS").to_camel_case(), "macOs");
+        assert_eq!(CaseFormatter::from("macOS").to_pascal_case(), "MacOs");
+    }
+
+    #[test]
+    fn test_convert_many() {
+        let processor = CaseFormatter::from("userProfileService");
+        let cases = [Case::Snake, Case::ScreamingKebab, Case::Title, Case::Snake];
+        let converted = processor.convert_many(&cases);
+
+        assert_eq!(converted.len(), 3);
+        for case in cases {
+            assert_eq!(
+                converted[&case],
+                processor.convert(case),
+                "Failed for case: {:?}",
+                case
+            );
+        }
+        assert_eq!(converted[&Case::ScreamingKebab], "USER-PROFILE-SERVICE");
+        assert!(!converted.contains_key(&Case::Camel));
+        assert!(processor.convert_many(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_digits_as_separators() {
+        let separators = SplitConfig {
+            digits_as_separators: true,
+            ..Default::default()
+        };
+        let boundaries = SplitConfig {
+            split_after_digits: true,
+            ..Default::default()
+        };
+        let test_cases = vec![
+            (
+                "section1subsection2",
+                "section_subsection",
+                "section1_subsection2",
+                "section1subsection2",
+            ),
+            (
+                "part12Detail",
+                "part_detail",
+                "part12_detail",
+                "part12detail",
+            ),
+            ("v2_api", "v_api", "v2_api", "v2_api"),
+            ("2024report", "report", "2024_report", "2024report"),
+            ("123", "", "123", "123"),
+        ];
+
+        for (input, as_separators, as_boundaries, default) in test_cases {
+            assert_eq!(
+                CaseFormatter::from_custom(input, &separators).to_snake_case(),
+                as_separators,
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                CaseFormatter::from_custom(input, &boundaries).to_snake_case(),
+                as_boundaries,
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                CaseFormatter::from(input).to_snake_case(),
+                default,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let both = SplitConfig {
+            split_after_digits: true,
+            ..separators
+        };
+        assert_eq!(
+            CaseFormatter::from_custom("section1subsection2", &both).words(),
+            ["section", "subsection"]
+        );
+    }
 }
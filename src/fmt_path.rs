@@ -1,39 +1,287 @@
+use std::ffi::{OsStr, OsString};
 use std::path::{Path, PathBuf};
 
+/// Characters disallowed in Windows filenames, stripped by `strip_unfriendly_chars`
+pub(crate) const UNFRIENDLY_CHARS: [char; 6] = ['*', '?', '"', '<', '>', '|'];
+
+/// Prefix of Windows verbatim paths, which bypass all normalization (`\\?\C:\dir`)
+const VERBATIM_PREFIX: &str = r"\\?\";
+
+/// Prefix of Windows device paths (`\\.\PIPE\name`)
+const DEVICE_PREFIX: &str = r"\\.\";
+
+/// Basenames reserved by Windows, regardless of case and extension
+const RESERVED_BASENAMES: [&str; 22] = [
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Check whether a single path component is a reserved Windows name (`CON`, `nul.txt`, ...)
+pub(crate) fn is_reserved_basename(component: &str) -> bool {
+    let basename = component.split('.').next().unwrap_or_default();
+    RESERVED_BASENAMES
+        .iter()
+        .any(|reserved| reserved.eq_ignore_ascii_case(basename))
+}
+
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub struct PathFormatConfig {
+    /// Whether to strip byte order marks (`\u{FEFF}`) at the very beginning of the path.
+    /// This runs before any other processing step.
+    pub strip_bom: bool,
+
     /// Whether to strip ANSI escape sequences (e.g., `\x1b[31m`, `\x1b[0m`).
     /// When the path string may contain terminal color codes, enabling this option will clean them up.
     #[cfg(feature = "strip-ansi")]
     pub strip_ansi: bool,
 
+    /// Whether to strip a leading `file://` URL scheme (`file:///home/doc.txt` -> `/home/doc.txt`).
+    /// A `localhost` authority is dropped as well, other hosts become a UNC path
+    /// (`file://server/share` -> `//server/share`), and Windows drive paths lose the slash
+    /// in front of the drive letter (`file:///C:/Users` -> `C:/Users`).
+    pub strip_file_scheme: bool,
+
+    /// Whether to strip the Windows verbatim prefix `\\?\`, returning the normal form
+    /// (`\\?\C:\dir` -> `C:/dir`, `\\?\UNC\server\share` -> `//server/share`), which is then
+    /// formatted like any other path. Otherwise verbatim paths, like device paths (`\\.\PIPE\name`),
+    /// are returned untouched, as Windows does not normalize them either.
+    pub strip_verbatim_prefix: bool,
+
+    /// Whether to strip the scheme of a URL such as `https://example.com/docs`, leaving
+    /// `example.com/docs`. The query string and fragment (`?q=1#top`) are dropped as well.
+    /// Any scheme of two or more characters followed by `://` counts, so drives are never mistaken for one.
+    pub strip_url_scheme: bool,
+
+    /// Whether to strip the scheme and host of a URL, keeping only its path:
+    /// `https://example.com/docs/api?q=1` becomes `/docs/api` and `https://example.com` becomes `/`.
+    /// The scheme goes with the host whether or not `strip_url_scheme` is enabled.
+    pub strip_url_host: bool,
+
     /// Whether to strip characters disallowed in Windows filenames (`*`, `?`, `"`, `<`, `>`, `|`).
     /// These characters typically have special meaning or are not allowed in filesystems.
     pub strip_unfriendly_chars: bool,
 
+    /// Whether to strip trailing dots and spaces from every component (`report. ` -> `report`),
+    /// which Windows rejects or silently drops. The special `.` and `..` components are kept.
+    /// A component made only of dots and spaces (`...`) is removed entirely, along with its separator.
+    pub trim_component_trailing_dots_spaces: bool,
+
     /// Whether to resolve parent directory references (`..`).
     /// When enabled, attempts to navigate upward in the path, e.g., `/a/b/../c` becomes `/a/c`.
     /// Note: This operation is based solely on the path string itself, without accessing the actual filesystem.
+    /// It follows the rules of [`resolve_dot_segments`], so doubled slashes are only collapsed by
+    /// `collapse_consecutive_slashes` and the result is the same on every platform.
     pub resolve_parent_dirs: bool,
 
+    /// Whether to drop current directory references (`.`), e.g. `a/./b` becomes `a/b`.
+    /// Independent of `resolve_parent_dirs`; with only this option, `..` is left alone.
+    pub collapse_cur_dir: bool,
+
     /// Whether to collapse consecutive forward slashes (`/`).
     /// For example, `/home//user` becomes `/home/user`.
     pub collapse_consecutive_slashes: bool,
 
+    /// Whether a UNC path (`\\server\share` or `//server/share`) keeps its leading double slash
+    /// when `collapse_consecutive_slashes` is enabled, so it does not turn into the absolute path
+    /// `/server/share`. The `//server/share` root is a floor that `..` cannot climb past either.
+    pub preserve_unc_prefix: bool,
+
     /// Whether to escape backslashes (`\`) to forward slashes (`/`).
     /// This helps unify Windows‑style paths to Unix style, facilitating cross‑platform handling.
     pub escape_backslashes: bool,
+
+    /// Case to fold the file extension into, if any.
+    /// Only the part after the last `.` of the final component is affected,
+    /// e.g. `/docs/README.MD` becomes `/docs/README.md` with [`ExtensionCase::Lower`].
+    pub extension_case: Option<ExtensionCase>,
+
+    /// Case to put a leading Windows drive letter into, if any (`c:/users` -> `C:/users`
+    /// with [`DriveCase::Upper`]). Drive-relative paths like `C:dir` count as well.
+    pub normalize_drive_letter: Option<DriveCase>,
+
+    /// Which dot of the final component starts the extension, used by `extension_case`
+    /// and [`with_extension`]. With [`ExtensionSeparatorPolicy::FirstDot`],
+    /// `archive.tar.GZ` has the extension `tar.GZ`.
+    pub extension_separator_policy: ExtensionSeparatorPolicy,
+
+    /// How to treat the trailing slash of the result.
+    /// The default [`TrailingSlashPolicy::Preserve`] keeps a trailing slash only if the input had one.
+    pub trailing_slash: TrailingSlashPolicy,
+
+    /// Whether to strip leading slashes, making absolute paths relative.
+    /// For example, `/home/user/file.txt` becomes `home/user/file.txt`.
+    /// Cannot be combined with `ensure_leading_slash`.
+    pub strip_leading_slash: bool,
+
+    /// Whether a path starting with `./` keeps exactly one leading `./` after normalization,
+    /// e.g. `././a/../b` becomes `./b` and `./` stays `./` instead of becoming empty.
+    /// Cannot be combined with `ensure_leading_slash`.
+    pub keep_leading_current_dir: bool,
+
+    /// Whether to prepend a slash to relative paths, making them absolute.
+    /// For example, `home/user/file.txt` becomes `/home/user/file.txt`; empty results stay empty.
+    /// Paths starting with a drive (`C:/dir`, `C:dir`) or with a `..` that could not be resolved
+    /// (`../a`) are left alone, a slash would change their meaning.
+    /// Cannot be combined with `strip_leading_slash` or `keep_leading_current_dir`.
+    pub ensure_leading_slash: bool,
+
+    /// Whether to canonicalize the path through the filesystem after string normalization.
+    /// This resolves symlinks and makes the path absolute, failing with
+    /// [`PathFormatError::IoError`] if the path does not exist.
+    #[cfg(feature = "fs")]
+    pub canonicalize: bool,
+
+    /// Whether to lowercase the whole resulting path, e.g. for case-insensitive comparisons.
+    /// `C:/Users/Test` becomes `c:/users/test`. This runs after every other pass
+    /// except `extension_case`, so a drive letter ends up lowercase as well.
+    pub lowercase: bool,
+
+    /// Whether `lowercase` only folds ASCII letters, leaving other characters untouched.
+    /// Otherwise full Unicode lowercasing is applied.
+    pub lowercase_ascii_only: bool,
+
+    /// Whether to fail with [`PathFormatError::HiddenNotAllowed`] when the final component
+    /// of the result is a dotfile, see [`is_hidden`].
+    pub reject_hidden: bool,
+
+    /// Whether to rename components that are reserved names on Windows (`CON`, `PRN`, `AUX`, `NUL`,
+    /// `COM1`-`COM9`, `LPT1`-`LPT9`, in any case and with any extension) by appending
+    /// `reserved_name_suffix` to the name before its extension: `logs/nul.log` becomes `logs/nul_.log`.
+    /// Only whole components are checked, so names merely containing a reserved one (`console`) are kept.
+    pub sanitize_reserved_names: bool,
+
+    /// Suffix appended to reserved names by `sanitize_reserved_names`, `_` by default.
+    pub reserved_name_suffix: &'static str,
+}
+
+impl PathFormatConfig {
+    /// Check that no contradictory options are enabled.
+    ///
+    /// Every formatting function calls this first, so a contradictory config fails with
+    /// [`PathFormatError::InvalidConfig`] instead of producing order-dependent output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use just_fmt::fmt_path::{PathFormatConfig, PathFormatConfigError};
+    /// assert!(PathFormatConfig::default().validate().is_ok());
+    ///
+    /// let config = PathFormatConfig {
+    ///     strip_leading_slash: true,
+    ///     ensure_leading_slash: true,
+    ///     ..Default::default()
+    /// };
+    /// assert!(matches!(
+    ///     config.validate(),
+    ///     Err(PathFormatConfigError::ConflictingOptions { .. })
+    /// ));
+    /// ```
+    pub fn validate(&self) -> Result<(), PathFormatConfigError> {
+        if self.strip_leading_slash && self.ensure_leading_slash {
+            return Err(PathFormatConfigError::ConflictingOptions {
+                first: "strip_leading_slash",
+                second: "ensure_leading_slash",
+                reason: "a leading slash cannot be both removed and required",
+            });
+        }
+        if self.keep_leading_current_dir && self.ensure_leading_slash {
+            return Err(PathFormatConfigError::ConflictingOptions {
+                first: "keep_leading_current_dir",
+                second: "ensure_leading_slash",
+                reason: "a path cannot start with both `./` and `/`",
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Trailing slash handling applied by [`PathFormatConfig::trailing_slash`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum TrailingSlashPolicy {
+    /// Keep a trailing slash only if the input had one (`a/b/` -> `a/b/`, `a/b` -> `a/b`)
+    #[default]
+    Preserve,
+
+    /// Always end the result with a slash (`a/b` -> `a/b/`).
+    /// Empty results stay empty, and so do paths that resolve to the current directory
+    /// (`.`, `./`, `a/..`), since `./` is itself formatted as the empty path.
+    AlwaysAdd,
+
+    /// Never end the result with a slash, except for a bare root like `/` or `C:/` (`a/b/` -> `a/b`)
+    AlwaysStrip,
+}
+
+/// Where the extension of a file name starts, see [`PathFormatConfig::extension_separator_policy`].
+///
+/// A leading dot never starts an extension (`.bashrc`), under every policy.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum ExtensionSeparatorPolicy {
+    /// Everything after the last dot (`file.txt.bak` -> `bak`)
+    #[default]
+    LastDot,
+
+    /// Everything after the first dot (`file.txt.bak` -> `txt.bak`)
+    FirstDot,
+
+    /// File names have no extension
+    None,
+}
+
+/// Case applied to drive letters by [`PathFormatConfig::normalize_drive_letter`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum DriveCase {
+    /// Uppercase the drive letter (`c:` -> `C:`), as most Windows tooling does
+    Upper,
+
+    /// Lowercase the drive letter (`C:` -> `c:`)
+    Lower,
+
+    /// Keep the drive letter as typed
+    #[default]
+    Preserve,
+}
+
+/// Case applied to file extensions by [`PathFormatConfig::extension_case`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ExtensionCase {
+    /// Fold the extension to lowercase (`.TXT` -> `.txt`)
+    Lower,
+
+    /// Fold the extension to uppercase (`.txt` -> `.TXT`)
+    Upper,
 }
 
 impl Default for PathFormatConfig {
     fn default() -> Self {
         Self {
+            strip_bom: true,
             #[cfg(feature = "strip-ansi")]
             strip_ansi: true,
+            strip_file_scheme: true,
+            strip_verbatim_prefix: false,
+            strip_url_scheme: false,
+            strip_url_host: false,
             strip_unfriendly_chars: true,
+            trim_component_trailing_dots_spaces: false,
             resolve_parent_dirs: true,
+            collapse_cur_dir: true,
             collapse_consecutive_slashes: true,
+            preserve_unc_prefix: true,
             escape_backslashes: true,
+            extension_case: None,
+            normalize_drive_letter: None,
+            extension_separator_policy: ExtensionSeparatorPolicy::LastDot,
+            trailing_slash: TrailingSlashPolicy::Preserve,
+            strip_leading_slash: false,
+            keep_leading_current_dir: false,
+            ensure_leading_slash: false,
+            #[cfg(feature = "fs")]
+            canonicalize: false,
+            lowercase: false,
+            lowercase_ascii_only: false,
+            reject_hidden: false,
+            sanitize_reserved_names: false,
+            reserved_name_suffix: "_",
         }
     }
 }
@@ -41,7 +289,7 @@ impl Default for PathFormatConfig {
 /// Normalize an input path string into a canonical, platform‑agnostic form.
 ///
 /// This function removes ANSI escape sequences (requires `strip-ansi` feature),
-/// unifies separators to `/`,
+/// strips a `file://` URL scheme, unifies separators to `/`,
 /// collapses duplicate slashes, strips unfriendly characters (`*`, `?`, `"`, `<`, `>`, `|`),
 /// resolves simple `..` components, and preserves a trailing slash when present.
 ///
@@ -51,14 +299,15 @@ impl Default for PathFormatConfig {
 ///
 /// ```
 /// # use just_fmt::fmt_path::fmt_path_str;
-/// # use just_fmt::fmt_path::FormatPathError;
+/// # use just_fmt::fmt_path::PathFormatError;
 ///
-/// # fn main() -> Result<(), FormatPathError> {
+/// # fn main() -> Result<(), PathFormatError> {
 /// assert_eq!(fmt_path_str("C:\\Users\\\\test")?, "C:/Users/test");
 /// assert_eq!(
 ///     fmt_path_str("/path/with/*unfriendly?chars")?,
 ///     "/path/with/unfriendlychars"
 /// );
+/// # #[cfg(feature = "strip-ansi")]
 /// assert_eq!(fmt_path_str("\x1b[31m/path\x1b[0m")?, "/path");
 /// assert_eq!(fmt_path_str("/home/user/dir/")?, "/home/user/dir/");
 /// assert_eq!(
@@ -93,145 +342,2961 @@ pub fn fmt_path_str_custom(
     path: impl Into<String>,
     config: &PathFormatConfig,
 ) -> Result<String, PathFormatError> {
-    let path_result = path.into();
-    let ends_with_slash = path_result.ends_with('/') || path_result.ends_with('\\');
+    format_path_str(path.into(), config, None)
+}
 
-    // ANSI Strip
+/// Normalize a path like [`fmt_path_str_custom`], then check the result with a custom rule.
+///
+/// `validate` receives the final normalized string, and its error is returned as is.
+/// This keeps application-specific rules out of [`PathFormatConfig`].
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::fmt_path::{fmt_path_str_validated, PathFormatConfig, PathFormatError};
+/// let within_srv = |path: &str| {
+///     if path.starts_with("/srv/") {
+///         Ok(())
+///     } else {
+///         Err(PathFormatError::JailEscape {
+///             attempted_path: path.to_string(),
+///         })
+///     }
+/// };
+/// let config = PathFormatConfig::default();
+/// assert_eq!(
+///     fmt_path_str_validated("/srv//www/../data", &config, within_srv).unwrap(),
+///     "/srv/data"
+/// );
+/// assert!(fmt_path_str_validated("/srv/../etc", &config, within_srv).is_err());
+/// ```
+pub fn fmt_path_str_validated<F>(
+    path: impl Into<String>,
+    config: &PathFormatConfig,
+    validate: F,
+) -> Result<String, PathFormatError>
+where
+    F: FnOnce(&str) -> Result<(), PathFormatError>,
+{
+    let formatted = fmt_path_str_custom(path, config)?;
+    validate(&formatted)?;
+    Ok(formatted)
+}
+
+/// Check whether the path is already in the form [`fmt_path_str_custom`] would produce.
+///
+/// Cheap textual checks reject obviously dirty paths without allocating;
+/// otherwise the path is normalized and compared. Paths that fail to normalize are not normalized.
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::fmt_path::{is_normalized, PathFormatConfig};
+/// let config = PathFormatConfig::default();
+/// assert!(is_normalized("/home/user/file.txt", &config));
+/// assert!(!is_normalized("/home//user/../file.txt", &config));
+/// ```
+pub fn is_normalized(path: &str, config: &PathFormatConfig) -> bool {
+    if is_namespaced(path) {
+        return fmt_path_str_custom(path, config).is_ok_and(|result| result == path);
+    }
     #[cfg(feature = "strip-ansi")]
-    let path_result = if config.strip_ansi {
-        let cleaned = strip_ansi_escapes::strip(&path_result);
-        String::from_utf8(cleaned).map_err(PathFormatError::InvalidUtf8)?
+    if config.strip_ansi && path.contains('\x1b') {
+        return false;
+    }
+    if config.escape_backslashes && path.contains('\\') {
+        return false;
+    }
+    let unc_body = match unc_root(path) {
+        Some(_) if config.preserve_unc_prefix => &path[1..],
+        _ => path,
+    };
+    if config.collapse_consecutive_slashes && unc_body.contains("//") {
+        return false;
+    }
+    if config.strip_unfriendly_chars && path.contains(UNFRIENDLY_CHARS) {
+        return false;
+    }
+
+    fmt_path_str_custom(path, config).is_ok_and(|result| result == path)
+}
+
+/// Check whether the final component of a path is hidden, i.e. a dotfile like `.gitignore`.
+///
+/// A trailing slash is ignored, so hidden directories count as well.
+/// The special components `.` and `..` are not hidden.
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::fmt_path::is_hidden;
+/// assert!(is_hidden("dir/.secret"));
+/// assert!(!is_hidden("a/b.txt"));
+/// assert!(!is_hidden(".."));
+/// ```
+pub fn is_hidden(path: &str) -> bool {
+    let name = path
+        .trim_end_matches(['/', '\\'])
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or_default();
+    name.starts_with('.') && name != "." && name != ".."
+}
+
+/// Check whether two paths are equal after normalizing both with the same config.
+///
+/// Enable [`PathFormatConfig::lowercase`] to ignore case and
+/// [`TrailingSlashPolicy::AlwaysStrip`] to ignore trailing slashes.
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::fmt_path::{paths_equal, PathFormatConfig, TrailingSlashPolicy};
+/// let config = PathFormatConfig {
+///     lowercase: true,
+///     trailing_slash: TrailingSlashPolicy::AlwaysStrip,
+///     ..Default::default()
+/// };
+/// assert!(paths_equal("/A/B/", "/a/b", &config).unwrap());
+/// assert!(!paths_equal("/A/B/", "/a/b", &PathFormatConfig::default()).unwrap());
+/// ```
+pub fn paths_equal(a: &str, b: &str, config: &PathFormatConfig) -> Result<bool, PathFormatError> {
+    Ok(fmt_path_str_custom(a, config)? == fmt_path_str_custom(b, config)?)
+}
+
+/// Normalize a path and shorten it to its last `keep` components for display.
+///
+/// When components were dropped the result starts with `.../`; a trailing slash is kept.
+/// Paths with at most `keep` components are returned normalized but otherwise unchanged.
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::fmt_path::{shorten_path, PathFormatConfig};
+/// let config = PathFormatConfig::default();
+/// assert_eq!(
+///     shorten_path("/home/user/project/src/main.rs", 3, &config).unwrap(),
+///     ".../project/src/main.rs"
+/// );
+/// assert_eq!(shorten_path("src/main.rs", 3, &config).unwrap(), "src/main.rs");
+/// ```
+pub fn shorten_path(
+    path: &str,
+    keep: usize,
+    config: &PathFormatConfig,
+) -> Result<String, PathFormatError> {
+    let normalized = fmt_path_str_custom(path, config)?;
+    let trailing_slash = normalized.len() > 1 && normalized.ends_with('/');
+    let components: Vec<&str> = normalized.split('/').filter(|c| !c.is_empty()).collect();
+
+    if components.len() <= keep {
+        return Ok(normalized);
+    }
+
+    let mut shortened = format!(".../{}", components[components.len() - keep..].join("/"));
+    if keep == 0 {
+        shortened.pop();
+    } else if trailing_slash {
+        shortened.push('/');
+    }
+    Ok(shortened)
+}
+
+/// Normalize `path` relative to a `jail` directory, failing if the result escapes the jail.
+///
+/// `path` is appended to the normalized `jail` (an absolute `path` is treated as relative to the jail),
+/// and the `.` and `..` segments of the joined path are resolved. The result must be the jail itself
+/// or lie inside it, otherwise [`PathFormatError::JailEscape`] is returned.
+/// The check is purely lexical: the filesystem is never accessed, so symlinks are not followed.
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::fmt_path::{fmt_path_str_jailed, PathFormatError};
+/// assert_eq!(
+///     fmt_path_str_jailed("css/../img/logo.png", "/srv/www").unwrap(),
+///     "/srv/www/img/logo.png"
+/// );
+/// assert!(matches!(
+///     fmt_path_str_jailed("../../etc/passwd", "/srv/www"),
+///     Err(PathFormatError::JailEscape { .. })
+/// ));
+/// ```
+pub fn fmt_path_str_jailed(path: &str, jail: &str) -> Result<String, PathFormatError> {
+    let jail = fmt_path_str(jail)?;
+    let unresolved = PathFormatConfig {
+        resolve_parent_dirs: false,
+        ..Default::default()
+    };
+    let path = path.trim_start_matches(['/', '\\']);
+    // A namespaced jail is kept verbatim, so the path is joined with its backslash separators
+    let namespaced = is_namespaced(&jail);
+    let joined = if jail.is_empty() || path.is_empty() {
+        format!("{}{}", jail, path)
+    } else if namespaced {
+        format!(
+            "{}\\{}",
+            jail.trim_end_matches('\\'),
+            path.replace('/', "\\")
+        )
+    } else {
+        format!("{}/{}", jail, path)
+    };
+    let joined = fmt_path_str_custom(joined, &unresolved)?;
+
+    // Namespaced paths come back untouched, so check a resolved copy with `/` separators
+    let (joined, checked, jail) = if namespaced {
+        let checked = resolve_dot_segments(&joined.replace('\\', "/"));
+        (joined, checked, jail.replace('\\', "/"))
+    } else {
+        let joined = resolve_dot_segments(&joined);
+        (joined.clone(), joined, jail)
+    };
+
+    let jail_root = jail.trim_end_matches('/');
+    let inside = if jail_root.is_empty() && !jail.starts_with('/') || jail_root == "." {
+        checked != ".." && !checked.starts_with("../")
+    } else {
+        checked.trim_end_matches('/') == jail_root
+            || checked.starts_with(&format!("{}/", jail_root))
+    };
+
+    if inside {
+        Ok(joined)
+    } else {
+        Err(PathFormatError::JailEscape {
+            attempted_path: joined,
+        })
+    }
+}
+
+/// Normalize an input path string like [`fmt_path_str_custom`],
+/// additionally reporting which normalization passes changed the path.
+///
+/// The steps are listed in the order they were applied.
+/// This is intended for debugging unexpected results; use [`fmt_path_str_custom`] otherwise.
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::fmt_path::{explain_fmt_path, PathFormatConfig, PathTransformStep};
+/// let (result, steps) = explain_fmt_path("a//b/../c", &PathFormatConfig::default()).unwrap();
+/// assert_eq!(result, "a/c");
+/// assert_eq!(
+///     steps,
+///     vec![
+///         PathTransformStep::CollapsedSlashes,
+///         PathTransformStep::ResolvedParent {
+///             from: "a/b/../c".to_string(),
+///             to: "a/c".to_string(),
+///         },
+///     ]
+/// );
+/// ```
+pub fn explain_fmt_path(
+    path: &str,
+    config: &PathFormatConfig,
+) -> Result<(String, Vec<PathTransformStep>), PathFormatError> {
+    let mut steps = Vec::new();
+    let result = format_path_str(path.to_string(), config, Some(&mut steps))?;
+    Ok((result, steps))
+}
+
+/// A normalization pass that changed the path, as reported by [`explain_fmt_path`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum PathTransformStep {
+    /// A leading byte order mark was removed
+    StrippedBom,
+
+    /// ANSI escape sequences were removed
+    StrippedAnsi,
+
+    /// A `file://` URL scheme was removed
+    StrippedFileScheme,
+
+    /// A Windows verbatim prefix (`\\?\`) was removed
+    StrippedVerbatimPrefix,
+
+    /// A URL scheme (`https://`) was removed
+    StrippedUrlScheme,
+
+    /// A URL host (`example.com`) was removed
+    StrippedUrlHost,
+
+    /// A URL query string or fragment (`?q=1#top`) was removed
+    StrippedUrlQuery,
+
+    /// Backslashes were converted to forward slashes
+    EscapedBackslashes,
+
+    /// Characters disallowed in Windows filenames were removed
+    StrippedUnfriendlyChars,
+
+    /// Trailing dots and spaces were trimmed from components
+    TrimmedComponents,
+
+    /// Consecutive slashes were collapsed into one
+    CollapsedSlashes,
+
+    /// `..` and `.` components were resolved
+    ResolvedParent { from: String, to: String },
+
+    /// The path was canonicalized through the filesystem
+    Canonicalized { from: String, to: String },
+
+    /// A trailing slash was added
+    AddedTrailingSlash,
+
+    /// A trailing slash was removed
+    StrippedTrailingSlash,
+
+    /// A bare current directory (`./`) was turned into an empty path
+    EmptiedCurrentDir,
+
+    /// Leading slashes were removed
+    StrippedLeadingSlash,
+
+    /// A leading slash was added
+    AddedLeadingSlash,
+
+    /// Components with reserved Windows names were renamed
+    SanitizedReservedNames,
+
+    /// The drive letter was recased
+    NormalizedDriveLetter,
+
+    /// The path was lowercased
+    Lowercased,
+
+    /// The file extension was case-folded
+    FoldedExtensionCase { from: String, to: String },
+}
+
+fn format_path_str(
+    path: String,
+    config: &PathFormatConfig,
+    mut trace: Option<&mut Vec<PathTransformStep>>,
+) -> Result<String, PathFormatError> {
+    config.validate()?;
+
+    let path = if config.strip_bom && path.starts_with('\u{FEFF}') {
+        record(&mut trace, PathTransformStep::StrippedBom);
+        path.trim_start_matches('\u{FEFF}').to_string()
     } else {
-        path_result
+        path
     };
 
-    let path_result = if config.escape_backslashes {
-        path_result.replace('\\', "/")
+    // ANSI Strip
+    #[cfg(feature = "strip-ansi")]
+    let path = if config.strip_ansi {
+        let cleaned = strip_ansi_escapes::strip(&path);
+        let cleaned =
+            String::from_utf8(cleaned).map_err(|source| PathFormatError::InvalidUtf8 {
+                source,
+                input_preview: input_preview(&path),
+            })?;
+        if cleaned != path {
+            record(&mut trace, PathTransformStep::StrippedAnsi);
+        }
+        cleaned
     } else {
-        path_result
+        path
     };
-    let mut result = String::new();
-    let mut prev_char = '\0';
 
-    for c in path_result.chars() {
-        if config.collapse_consecutive_slashes && c == '/' && prev_char == '/' {
-            continue;
+    let mut result = path;
+
+    if config.strip_file_scheme
+        && let Some(stripped) = strip_file_scheme(&result)
+    {
+        result = stripped.to_string();
+        record(&mut trace, PathTransformStep::StrippedFileScheme);
+    }
+
+    if (config.strip_url_scheme || config.strip_url_host)
+        && let Some((host, url_path)) = split_url(&result)
+    {
+        let without_query = url_path.split(['?', '#']).next().unwrap_or_default();
+        let query_stripped = without_query.len() != url_path.len();
+        result = if !config.strip_url_host {
+            format!("{}{}", host, without_query)
+        } else if without_query.is_empty() {
+            "/".to_string()
+        } else {
+            without_query.to_string()
+        };
+        record(&mut trace, PathTransformStep::StrippedUrlScheme);
+        if config.strip_url_host {
+            record(&mut trace, PathTransformStep::StrippedUrlHost);
         }
-        result.push(c);
-        prev_char = c;
+        if query_stripped {
+            record(&mut trace, PathTransformStep::StrippedUrlQuery);
+        }
+    }
+
+    if config.strip_verbatim_prefix
+        && let Some(rest) = result.strip_prefix(VERBATIM_PREFIX)
+    {
+        result = match rest.get(..4) {
+            Some(unc) if unc.eq_ignore_ascii_case(r"UNC\") => format!(r"\\{}", &rest[4..]),
+            _ => rest.to_string(),
+        };
+        record(&mut trace, PathTransformStep::StrippedVerbatimPrefix);
+    }
+
+    // Windows applies no normalization to verbatim and device paths, neither do we
+    if is_namespaced(&result) {
+        return validate(result, config);
+    }
+
+    if config.escape_backslashes && result.contains('\\') {
+        result = result.replace('\\', "/");
+        record(&mut trace, PathTransformStep::EscapedBackslashes);
     }
 
-    if config.strip_unfriendly_chars {
-        let unfriendly_chars = ['*', '?', '"', '<', '>', '|'];
+    // Detected once escape sequences are gone and backslashes are separators,
+    // so `dir\`, `dir/\x1b[0m` and `dir/` all keep their trailing slash
+    let ends_with_slash = result.ends_with('/');
+    let starts_with_current_dir = config.keep_leading_current_dir && result.starts_with("./");
+
+    // Stripped before collapsing, so removing the `"` of `a/"/b` cannot leave a `//` behind
+    if config.strip_unfriendly_chars && result.contains(UNFRIENDLY_CHARS) {
         result = result
             .chars()
-            .filter(|c| !unfriendly_chars.contains(c))
+            .filter(|c| !UNFRIENDLY_CHARS.contains(c))
             .collect();
+        record(&mut trace, PathTransformStep::StrippedUnfriendlyChars);
     }
 
-    // Handle ".." path components
-    let path_buf = PathBuf::from(&result);
-    let normalized_path = if config.resolve_parent_dirs {
-        normalize_path(&path_buf)
-    } else {
-        path_buf
-    };
-    result = normalized_path.to_string_lossy().replace('\\', "/");
+    let is_unc = config.preserve_unc_prefix && unc_root(&result).is_some();
 
-    // Restore trailing slash if original path had one
-    if ends_with_slash && !result.ends_with('/') {
-        result.push('/');
+    if config.collapse_consecutive_slashes && result.contains("//") {
+        let mut collapsed = String::with_capacity(result.len());
+        let mut prev_char = '\0';
+
+        for c in result.chars() {
+            if c == '/' && prev_char == '/' {
+                continue;
+            }
+            collapsed.push(c);
+            prev_char = c;
+        }
+        // Put back the second slash of a UNC prefix, `//server/share` is not `/server/share`
+        if is_unc {
+            collapsed.insert(0, '/');
+        }
+        if collapsed != result {
+            record(&mut trace, PathTransformStep::CollapsedSlashes);
+        }
+        result = collapsed;
     }
 
-    // Special case: when result is only "./", return ""
-    if result == "./" {
-        return Ok(String::new());
+    if config.trim_component_trailing_dots_spaces {
+        let trimmed = trim_components(&result);
+        if trimmed != result {
+            record(&mut trace, PathTransformStep::TrimmedComponents);
+        }
+        result = trimmed;
     }
 
-    Ok(result)
-}
+    // Handle "." and ".." path components, an empty path stays empty
+    if result.is_empty() {
+        return Ok(result);
+    }
+    let normalized = if config.resolve_parent_dirs || config.collapse_cur_dir {
+        resolve_segments(&result, config.resolve_parent_dirs, config.collapse_cur_dir)
+    } else {
+        result.clone()
+    };
+    if (config.resolve_parent_dirs || config.collapse_cur_dir)
+        && normalized.trim_end_matches('/') != result.trim_end_matches('/')
+    {
+        record_with(&mut trace, || PathTransformStep::ResolvedParent {
+            from: result.clone(),
+            to: normalized.clone(),
+        });
+    }
+    result = normalized;
 
-/// Normalize path by resolving ".." components without requiring file system access
-fn normalize_path(path: &Path) -> PathBuf {
-    let mut components = Vec::new();
+    // Resolve symlinks through the filesystem
+    #[cfg(feature = "fs")]
+    if config.canonicalize {
+        let canonical = std::fs::canonicalize(&result)?
+            .to_string_lossy()
+            .to_string();
+        let canonical = if config.escape_backslashes {
+            canonical.replace('\\', "/")
+        } else {
+            canonical
+        };
+        if canonical != result {
+            record_with(&mut trace, || PathTransformStep::Canonicalized {
+                from: result.clone(),
+                to: canonical.clone(),
+            });
+        }
+        result = canonical;
+    }
 
-    for component in path.components() {
-        match component {
-            std::path::Component::ParentDir => {
-                if !components.is_empty() {
-                    components.pop();
-                }
+    // Apply the trailing slash policy
+    match config.trailing_slash {
+        TrailingSlashPolicy::Preserve => {
+            // Restore trailing slash if original path had one
+            if ends_with_slash && !result.ends_with('/') {
+                result.push('/');
+            }
+        }
+        TrailingSlashPolicy::AlwaysAdd => {
+            if !result.ends_with('/') {
+                result.push('/');
+                record(&mut trace, PathTransformStep::AddedTrailingSlash);
             }
-            std::path::Component::CurDir => {
-                // Skip current directory components
+        }
+        TrailingSlashPolicy::AlwaysStrip => {
+            if ends_with_slash && !is_root(&result) {
+                record(&mut trace, PathTransformStep::StrippedTrailingSlash);
             }
-            _ => {
-                components.push(component);
+            while result.ends_with('/') && !is_root(&result) {
+                result.pop();
             }
         }
     }
 
-    if components.is_empty() {
-        PathBuf::from(".")
-    } else {
-        components.iter().collect()
+    if config.strip_leading_slash && result.starts_with('/') {
+        result = result.trim_start_matches('/').to_string();
+        record(&mut trace, PathTransformStep::StrippedLeadingSlash);
     }
-}
-
-/// Format a [`PathBuf`] into its canonical string form and convert it back.
-///
-/// This is a convenience wrapper around [`fmt_path_str`], preserving
-/// the semantics of [`PathBuf`] while applying the same normalization rules:
-/// - normalize separators to `/`
-/// - remove duplicated separators
-/// - strip ANSI escape sequences
-/// - remove unfriendly characters (`*`, `?`, etc.)
-/// - resolve simple `..` segments
-pub fn fmt_path(path: impl Into<PathBuf>) -> Result<PathBuf, PathFormatError> {
-    let path_str = fmt_path_str(path.into().display().to_string())?;
-    Ok(PathBuf::from(path_str))
-}
-
-/// Format a [`PathBuf`] into its canonical string form and convert it back.
-///
-/// Unlike `fmt_path`,
-/// this method uses `PathFormatConfig` to precisely control
-/// what should be processed
-pub fn fmt_path_custom(
-    path: impl Into<PathBuf>,
-    config: &PathFormatConfig,
-) -> Result<PathBuf, PathFormatError> {
-    let path_str = fmt_path_str_custom(path.into().display().to_string(), config)?;
-    Ok(PathBuf::from(path_str))
-}
 
-/// Error type for path formatting operations.
-#[derive(Debug)]
-pub enum PathFormatError {
-    /// The input string contained invalid UTF-8 after stripping ANSI escape sequences.
-    InvalidUtf8(std::string::FromUtf8Error),
-}
+    if starts_with_current_dir {
+        // Put back the single `./` that resolution removed, unless `..` already marks it relative
+        if result == "." && config.trailing_slash != TrailingSlashPolicy::AlwaysStrip {
+            result.push('/');
+        } else if !matches!(result.as_str(), "." | "./" | "..")
+            && !result.starts_with("./")
+            && !result.starts_with("../")
+        {
+            result.insert_str(0, "./");
+        }
+    } else if result == "./" {
+        // Special case: when result is only "./", return ""
+        record(&mut trace, PathTransformStep::EmptiedCurrentDir);
+        return Ok(String::new());
+    }
 
-impl std::fmt::Display for PathFormatError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            PathFormatError::InvalidUtf8(e) => {
-                write!(f, "Invalid UTF-8 after ANSI stripping: {}", e)
-            }
+    if config.ensure_leading_slash
+        && !result.is_empty()
+        && !result.starts_with('/')
+        && !result.get(..2).is_some_and(is_drive)
+        // A leading `..` would be clamped at the root, silently changing the target
+        && result.split('/').find(|segment| *segment != ".") != Some("..")
+    {
+        result.insert(0, '/');
+        // Leading `.` segments now sit at the root and resolve like the rest of the path
+        if (config.resolve_parent_dirs || config.collapse_cur_dir) && result.starts_with("/.") {
+            result = resolve_segments(&result, config.resolve_parent_dirs, config.collapse_cur_dir);
         }
+        record(&mut trace, PathTransformStep::AddedLeadingSlash);
     }
-}
 
-impl std::error::Error for PathFormatError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        match self {
-            PathFormatError::InvalidUtf8(e) => Some(e),
+    if let Some(drive_case) = config.normalize_drive_letter
+        && result.get(..2).is_some_and(is_drive)
+    {
+        let letter = &result[..1];
+        let recased = match drive_case {
+            DriveCase::Upper => letter.to_ascii_uppercase(),
+            DriveCase::Lower => letter.to_ascii_lowercase(),
+            DriveCase::Preserve => letter.to_string(),
+        };
+        if recased != letter {
+            result.replace_range(..1, &recased);
+            record(&mut trace, PathTransformStep::NormalizedDriveLetter);
         }
     }
-}
 
-impl From<std::string::FromUtf8Error> for PathFormatError {
-    fn from(e: std::string::FromUtf8Error) -> Self {
-        PathFormatError::InvalidUtf8(e)
+    if config.sanitize_reserved_names && result.split('/').any(is_reserved_basename) {
+        result = result
+            .split('/')
+            .map(|component| {
+                if is_reserved_basename(component) {
+                    let (name, extension) =
+                        component.split_at(component.find('.').unwrap_or(component.len()));
+                    format!("{}{}{}", name, config.reserved_name_suffix, extension)
+                } else {
+                    component.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("/");
+        record(&mut trace, PathTransformStep::SanitizedReservedNames);
+    }
+
+    if config.lowercase {
+        let lowercased = if config.lowercase_ascii_only {
+            result.to_ascii_lowercase()
+        } else {
+            result.to_lowercase()
+        };
+        if lowercased != result {
+            record(&mut trace, PathTransformStep::Lowercased);
+        }
+        result = lowercased;
+    }
+
+    if let Some(extension_case) = config.extension_case {
+        let folded =
+            fold_extension_case(&result, extension_case, config.extension_separator_policy);
+        if folded != result {
+            record_with(&mut trace, || PathTransformStep::FoldedExtensionCase {
+                from: result.clone(),
+                to: folded.clone(),
+            });
+        }
+        result = folded;
+    }
+
+    validate(result, config)
+}
+
+/// Run the checks that reject a formatted path
+fn validate(result: String, config: &PathFormatConfig) -> Result<String, PathFormatError> {
+    if config.reject_hidden && is_hidden(&result) {
+        return Err(PathFormatError::HiddenNotAllowed { path: result });
+    }
+
+    Ok(result)
+}
+
+/// Record a step when tracing is enabled
+fn record(trace: &mut Option<&mut Vec<PathTransformStep>>, step: PathTransformStep) {
+    record_with(trace, || step);
+}
+
+/// Record a lazily built step when tracing is enabled
+fn record_with(
+    trace: &mut Option<&mut Vec<PathTransformStep>>,
+    step: impl FnOnce() -> PathTransformStep,
+) {
+    if let Some(trace) = trace {
+        trace.push(step());
+    }
+}
+
+/// Strip trailing dots and spaces from every component other than `.` and `..`,
+/// removing components left empty
+fn trim_components(path: &str) -> String {
+    let mut kept: Vec<&str> = Vec::new();
+    let mut removed_last = false;
+    for component in path.split('/') {
+        removed_last = false;
+        match component {
+            "" | "." | ".." => kept.push(component),
+            _ => match component.trim_end_matches(['.', ' ']) {
+                "" => removed_last = true,
+                trimmed => kept.push(trimmed),
+            },
+        }
+    }
+
+    let mut trimmed = kept.join("/");
+    // A removed final component leaves a root behind, `/...` is `/` and `C:/...` is `C:/`
+    if removed_last && matches!(kept.as_slice(), [root] if root.is_empty() || is_drive(root)) {
+        trimmed.push('/');
+    }
+    trimmed
+}
+
+/// Longest input kept by the preview of [`PathFormatError::InvalidUtf8`], in characters
+const INPUT_PREVIEW_LEN: usize = 64;
+
+/// Escape and truncate an input so it can be logged safely
+fn input_preview(input: &str) -> String {
+    let mut preview: String = input
+        .chars()
+        .take(INPUT_PREVIEW_LEN)
+        .flat_map(char::escape_debug)
+        .collect();
+    if input.chars().nth(INPUT_PREVIEW_LEN).is_some() {
+        preview.push_str("...");
+    }
+    preview
+}
+
+/// Strip a `file://` URL scheme, returning `None` if the path has none
+fn strip_file_scheme(path: &str) -> Option<&str> {
+    let scheme = path.get(..7)?;
+    if !scheme.eq_ignore_ascii_case("file://") {
+        return None;
+    }
+
+    let mut rest = &path[7..];
+    let authority = &rest[..rest.find('/').unwrap_or(rest.len())];
+    if authority.eq_ignore_ascii_case("localhost") {
+        rest = &rest[9..];
+    } else if !authority.is_empty() {
+        // Any other host names a network location, `file://server/share` is `//server/share`
+        return Some(&path[5..]);
+    }
+
+    // `file:///C:/Users` names the drive path `C:/Users`
+    let bytes = rest.as_bytes();
+    if bytes.len() >= 3 && bytes[0] == b'/' && bytes[1].is_ascii_alphabetic() && bytes[2] == b':' {
+        rest = &rest[1..];
+    }
+
+    Some(rest)
+}
+
+/// Split a URL into its host and path (query and fragment included), returning `None` if it is not one
+///
+/// The scheme must be at least two characters long, so `C://dir` is not a URL.
+fn split_url(path: &str) -> Option<(&str, &str)> {
+    let (scheme, rest) = path.split_once("://")?;
+    let mut chars = scheme.chars();
+    let valid_scheme = scheme.len() >= 2
+        && chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    if !valid_scheme {
+        return None;
+    }
+
+    let host_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    Some(rest.split_at(host_end))
+}
+
+/// Fold the extension of the final path component into the given case
+fn fold_extension_case(
+    path: &str,
+    extension_case: ExtensionCase,
+    policy: ExtensionSeparatorPolicy,
+) -> String {
+    match extension_start(path, policy) {
+        Some(split) => {
+            let extension = match extension_case {
+                ExtensionCase::Lower => path[split..].to_lowercase(),
+                ExtensionCase::Upper => path[split..].to_uppercase(),
+            };
+            format!("{}{}", &path[..split], extension)
+        }
+        None => path.to_string(),
+    }
+}
+
+/// Byte offset of the extension of the final path component, after its `.`
+fn extension_start(path: &str, policy: ExtensionSeparatorPolicy) -> Option<usize> {
+    // Directories have no extension
+    if path.ends_with('/') {
+        return None;
+    }
+
+    let file_name_start = path.rfind('/').map(|i| i + 1).unwrap_or(0);
+    let file_name = &path[file_name_start..];
+
+    // A leading dot marks a hidden file (`.bashrc`), not an extension
+    let stem_start = usize::from(file_name.starts_with('.'));
+    let dot = match policy {
+        ExtensionSeparatorPolicy::LastDot => file_name.rfind('.'),
+        ExtensionSeparatorPolicy::FirstDot => file_name[stem_start..]
+            .find('.')
+            .map(|dot| dot + stem_start),
+        ExtensionSeparatorPolicy::None => None,
+    };
+    match dot {
+        Some(dot) if dot > 0 && dot + 1 < file_name.len() => Some(file_name_start + dot + 1),
+        _ => None,
+    }
+}
+
+/// Get the extension of the final component of a `/`-separated path, without the dot.
+///
+/// Directories (a trailing `/`), hidden files without a further dot (`.bashrc`)
+/// and names ending with a dot have no extension. Only the last extension is returned.
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::fmt_path::path_extension;
+/// assert_eq!(path_extension("a/b.txt"), Some("txt"));
+/// assert_eq!(path_extension("a.tar.gz"), Some("gz"));
+/// assert_eq!(path_extension(".gitignore"), None);
+/// assert_eq!(path_extension("dir.d/"), None);
+/// ```
+pub fn path_extension(path: &str) -> Option<&str> {
+    extension_start(path, ExtensionSeparatorPolicy::LastDot).map(|start| &path[start..])
+}
+
+/// Normalize a path and replace the extension of its final component.
+///
+/// The extension is found according to [`PathFormatConfig::extension_separator_policy`].
+/// A missing extension is appended, and an empty `extension` removes it; a leading `.`
+/// in `extension` is ignored. Directories and paths without a file name (`.`, `..`)
+/// are returned normalized but otherwise unchanged.
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::fmt_path::{with_extension, PathFormatConfig};
+/// let config = PathFormatConfig::default();
+/// assert_eq!(with_extension("a//b.txt", "md", &config).unwrap(), "a/b.md");
+/// assert_eq!(with_extension(".bashrc", ".bak", &config).unwrap(), ".bashrc.bak");
+/// assert_eq!(with_extension("a.tar.gz", "", &config).unwrap(), "a.tar");
+/// ```
+pub fn with_extension(
+    path: &str,
+    extension: &str,
+    config: &PathFormatConfig,
+) -> Result<String, PathFormatError> {
+    let mut result = fmt_path_str_custom(path, config)?;
+    let file_name = result.rsplit('/').next().unwrap_or_default();
+    if matches!(file_name, "" | "." | "..") {
+        return Ok(result);
+    }
+
+    if let Some(start) = extension_start(&result, config.extension_separator_policy) {
+        result.truncate(start - 1);
+    } else if result.ends_with('.') {
+        result.pop();
+    }
+
+    let extension = extension.trim_start_matches('.');
+    if !extension.is_empty() {
+        result.push('.');
+        result.push_str(extension);
+    }
+    Ok(result)
+}
+
+/// Convert backslashes to `/` and collapse runs of separators into one, in a single pass.
+///
+/// This is purely textual: nothing else is stripped or resolved, and even the double slash
+/// of a UNC path is collapsed. Use [`fmt_path_str`] for a full normalization.
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::fmt_path::normalize_separators;
+/// assert_eq!(normalize_separators("a\\\\b//c"), "a/b/c");
+/// assert_eq!(normalize_separators("C:\\dir\\..\\*.txt"), "C:/dir/../*.txt");
+/// ```
+pub fn normalize_separators(path: &str) -> String {
+    let mut normalized = String::with_capacity(path.len());
+    for c in path.chars() {
+        if c != '/' && c != '\\' {
+            normalized.push(c);
+        } else if !normalized.ends_with('/') {
+            normalized.push('/');
+        }
+    }
+    normalized
+}
+
+/// Resolve `.` and `..` segments of a `/`-separated path, purely on the string.
+///
+/// Segments are evaluated left to right: `.` is dropped and `..` removes the segment before it.
+/// A `..` that has nothing left to remove is kept in relative paths (`../x` stays `../x`),
+/// while the root of an absolute path (`/`, a drive like `C:/` or a UNC share like `//server/share`)
+/// is a floor it cannot climb past (`/../etc` becomes `/etc`). A drive-relative path (`C:dir/..`)
+/// keeps its drive in front of the rest, which resolves like a relative path (`C:.`). Empty segments and a trailing slash are kept as they are,
+/// and a path resolving to nothing becomes `.`. No other normalization is applied,
+/// and the result is the same on every platform.
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::fmt_path::resolve_dot_segments;
+/// assert_eq!(resolve_dot_segments("a/b/../c"), "a/c");
+/// assert_eq!(resolve_dot_segments("../x"), "../x");
+/// assert_eq!(resolve_dot_segments("/a/./b/"), "/a/b/");
+/// assert_eq!(resolve_dot_segments("C:/a/../../b"), "C:/b");
+/// assert_eq!(resolve_dot_segments("//server/share/../other"), "//server/share/other");
+/// ```
+pub fn resolve_dot_segments(path: &str) -> String {
+    resolve_segments(path, true, true)
+}
+
+/// Resolve `..` segments when `parent_dirs` is set and drop `.` segments when `cur_dirs` is set,
+/// following the rules of [`resolve_dot_segments`]
+///
+/// A `..` removes the closest segment before it that is not empty, `.` or `..`, so kept `.`
+/// segments and doubled slashes never absorb it: `a/./..` becomes `.` with `cur_dirs` off, and
+/// `a/b//../c` becomes `a/c`. Doubled slashes before the removed segment are kept.
+fn resolve_segments(path: &str, parent_dirs: bool, cur_dirs: bool) -> String {
+    // A drive-relative path (`C:dir`) keeps its drive in front of the resolved relative rest
+    if path.len() > 2 && path.get(..2).is_some_and(is_drive) && !path[2..].starts_with('/') {
+        return format!(
+            "{}{}",
+            &path[..2],
+            resolve_segments(&path[2..], parent_dirs, cur_dirs)
+        );
+    }
+
+    // The root of an absolute path can never be removed
+    let (root, rest) = match unc_root(path) {
+        Some(root) if root.len() == path.len() => return path.to_string(),
+        Some(root) => (Some(root), &path[root.len() + 1..]),
+        None => match path.split_once('/') {
+            Some(("", rest)) => (Some(""), rest),
+            Some((first, rest)) if is_drive(first) => (Some(first), rest),
+            _ => (None, path),
+        },
+    };
+
+    let mut resolved: Vec<&str> = Vec::new();
+    for segment in rest.split('/') {
+        match segment {
+            "." if cur_dirs => {}
+            ".." if parent_dirs => {
+                match resolved
+                    .iter()
+                    .rposition(|&segment| !segment.is_empty() && segment != "." && segment != "..")
+                {
+                    Some(index) => {
+                        let tail = resolved.split_off(index + 1);
+                        resolved.pop();
+                        resolved.extend(tail.into_iter().filter(|segment| !segment.is_empty()));
+                    }
+                    None if root.is_some() => {}
+                    None => resolved.push(".."),
+                }
+            }
+            _ => resolved.push(segment),
+        }
+    }
+
+    match root {
+        Some(root) => format!("{}/{}", root, resolved.join("/")),
+        None if resolved.iter().all(|segment| segment.is_empty()) => ".".to_string(),
+        None => resolved.join("/"),
+    }
+}
+
+/// Get the `//server/share` root of a UNC path, or `//server` when it names no share
+///
+/// The server must be a name, so `///a`, `//./a` and `//../a` are not UNC paths.
+fn unc_root(path: &str) -> Option<&str> {
+    let rest = path.strip_prefix("//")?;
+    let mut parts = rest.splitn(3, '/');
+    let server = parts
+        .next()
+        .filter(|server| !matches!(*server, "" | "." | ".."))?;
+    let len = match parts.next() {
+        Some(share) if !matches!(share, "" | "." | "..") => 2 + server.len() + 1 + share.len(),
+        _ => 2 + server.len(),
+    };
+    Some(&path[..len])
+}
+
+/// Check whether a path is in the Windows verbatim (`\\?\`) or device (`\\.\`) namespace
+fn is_namespaced(path: &str) -> bool {
+    path.starts_with(VERBATIM_PREFIX) || path.starts_with(DEVICE_PREFIX)
+}
+
+/// Check whether a path is a bare root (`/` or `C:/`)
+fn is_root(path: &str) -> bool {
+    path == "/" || path.strip_suffix('/').is_some_and(is_drive)
+}
+
+/// Check whether a path segment is a Windows drive (`C:`)
+fn is_drive(segment: &str) -> bool {
+    let bytes = segment.as_bytes();
+    bytes.len() == 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+}
+
+/// Format a [`PathBuf`] into its canonical string form and convert it back.
+///
+/// This is a convenience wrapper around [`fmt_path_str`], preserving
+/// the semantics of [`PathBuf`] while applying the same normalization rules:
+/// - normalize separators to `/`
+/// - remove duplicated separators
+/// - strip ANSI escape sequences
+/// - remove unfriendly characters (`*`, `?`, etc.)
+/// - resolve simple `..` segments
+pub fn fmt_path(path: impl Into<PathBuf>) -> Result<PathBuf, PathFormatError> {
+    let path_str = fmt_path_str(path.into().display().to_string())?;
+    Ok(PathBuf::from(path_str))
+}
+
+/// Format a [`PathBuf`] into its canonical string form and convert it back.
+///
+/// Unlike `fmt_path`,
+/// this method uses `PathFormatConfig` to precisely control
+/// what should be processed
+pub fn fmt_path_custom(
+    path: impl Into<PathBuf>,
+    config: &PathFormatConfig,
+) -> Result<PathBuf, PathFormatError> {
+    let path_str = fmt_path_str_custom(path.into().display().to_string(), config)?;
+    Ok(PathBuf::from(path_str))
+}
+
+/// Format an [`OsStr`] path into its canonical string form and convert it back.
+///
+/// Like [`fmt_path`], this is a convenience wrapper around [`fmt_path_str`].
+/// The input is converted with [`OsStr::to_string_lossy`], so invalid Unicode
+/// is replaced by `U+FFFD` before normalization.
+///
+/// # Examples
+///
+/// ```
+/// # use std::ffi::{OsStr, OsString};
+/// # use just_fmt::fmt_path::fmt_path_os;
+/// assert_eq!(
+///     fmt_path_os(OsStr::new("C:\\Users\\\\test")).unwrap(),
+///     OsString::from("C:/Users/test")
+/// );
+/// ```
+pub fn fmt_path_os(path: impl AsRef<OsStr>) -> Result<OsString, PathFormatError> {
+    fmt_path_os_custom(path, &PathFormatConfig::default())
+}
+
+/// Format an [`OsStr`] path into its canonical string form and convert it back.
+///
+/// Unlike `fmt_path_os`,
+/// this method uses `PathFormatConfig` to precisely control
+/// what should be processed
+pub fn fmt_path_os_custom(
+    path: impl AsRef<OsStr>,
+    config: &PathFormatConfig,
+) -> Result<OsString, PathFormatError> {
+    let path_str = fmt_path_str_custom(path.as_ref().to_string_lossy(), config)?;
+    Ok(OsString::from(path_str))
+}
+
+/// Path formatting called directly on strings and paths
+///
+/// Strings are formatted with [`fmt_path_str_custom`] and return a [`String`],
+/// paths are formatted with [`fmt_path_custom`] and return a [`PathBuf`].
+///
+/// # Examples
+///
+/// ```
+/// # use std::path::{Path, PathBuf};
+/// use just_fmt::fmt_path::PathFormatExt;
+/// # fn main() -> Result<(), just_fmt::fmt_path::PathFormatError> {
+/// assert_eq!("C:\\a\\\\b".fmt_path()?, "C:/a/b");
+/// assert_eq!(Path::new("a/./b/../c").fmt_path()?, PathBuf::from("a/c"));
+/// # Ok(())
+/// # }
+/// ```
+pub trait PathFormatExt {
+    /// The formatted form, matching the kind of input
+    type Output;
+
+    /// Format with the default config, see [`fmt_path_str`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use just_fmt::fmt_path::PathFormatExt;
+    /// assert_eq!(String::from("./home//path/").fmt_path().unwrap(), "home/path/");
+    /// ```
+    fn fmt_path(&self) -> Result<Self::Output, PathFormatError> {
+        self.fmt_path_custom(&PathFormatConfig::default())
+    }
+
+    /// Format with the given config, see [`fmt_path_str_custom`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::path::PathBuf;
+    /// use just_fmt::fmt_path::{PathFormatConfig, PathFormatExt};
+    /// let config = PathFormatConfig {
+    ///     lowercase: true,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     PathBuf::from("/Home/User").fmt_path_custom(&config).unwrap(),
+    ///     PathBuf::from("/home/user")
+    /// );
+    /// ```
+    fn fmt_path_custom(&self, config: &PathFormatConfig) -> Result<Self::Output, PathFormatError>;
+}
+
+impl PathFormatExt for &str {
+    type Output = String;
+
+    fn fmt_path_custom(&self, config: &PathFormatConfig) -> Result<String, PathFormatError> {
+        fmt_path_str_custom(*self, config)
+    }
+}
+
+impl PathFormatExt for String {
+    type Output = String;
+
+    fn fmt_path_custom(&self, config: &PathFormatConfig) -> Result<String, PathFormatError> {
+        fmt_path_str_custom(self.as_str(), config)
+    }
+}
+
+impl PathFormatExt for &Path {
+    type Output = PathBuf;
+
+    fn fmt_path_custom(&self, config: &PathFormatConfig) -> Result<PathBuf, PathFormatError> {
+        fmt_path_custom(*self, config)
+    }
+}
+
+impl PathFormatExt for PathBuf {
+    type Output = PathBuf;
+
+    fn fmt_path_custom(&self, config: &PathFormatConfig) -> Result<PathBuf, PathFormatError> {
+        fmt_path_custom(self.as_path(), config)
+    }
+}
+
+/// A path string already formatted by [`fmt_path_str`]
+///
+/// It can only be built through `TryFrom`, so holding one guarantees the path is normalized
+/// with the default [`PathFormatConfig`].
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::fmt_path::{NormalizedPath, PathFormatError};
+/// # fn main() -> Result<(), PathFormatError> {
+/// let path = NormalizedPath::try_from("/home/user/../docs/")?;
+/// assert_eq!(path.as_str(), "/home/docs/");
+/// assert!(path.starts_with("/home"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NormalizedPath(String);
+
+impl NormalizedPath {
+    /// Get the normalized path string
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Get the normalized path as a [`Path`]
+    pub fn as_path(&self) -> &Path {
+        Path::new(&self.0)
+    }
+
+    /// Unwrap the normalized path string
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl std::ops::Deref for NormalizedPath {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<str> for NormalizedPath {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<Path> for NormalizedPath {
+    fn as_ref(&self) -> &Path {
+        self.as_path()
+    }
+}
+
+impl std::fmt::Display for NormalizedPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<NormalizedPath> for String {
+    fn from(value: NormalizedPath) -> Self {
+        value.0
+    }
+}
+
+impl From<NormalizedPath> for PathBuf {
+    fn from(value: NormalizedPath) -> Self {
+        PathBuf::from(value.0)
+    }
+}
+
+impl TryFrom<&str> for NormalizedPath {
+    type Error = PathFormatError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        fmt_path_str(value).map(Self)
+    }
+}
+
+impl TryFrom<String> for NormalizedPath {
+    type Error = PathFormatError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        fmt_path_str(value).map(Self)
+    }
+}
+
+impl TryFrom<&Path> for NormalizedPath {
+    type Error = PathFormatError;
+
+    fn try_from(value: &Path) -> Result<Self, Self::Error> {
+        fmt_path_str(value.display().to_string()).map(Self)
+    }
+}
+
+impl TryFrom<PathBuf> for NormalizedPath {
+    type Error = PathFormatError;
+
+    fn try_from(value: PathBuf) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_path())
+    }
+}
+
+/// Error type for path formatting operations.
+#[derive(Debug)]
+pub enum PathFormatError {
+    /// The input string contained invalid UTF-8 after stripping ANSI escape sequences.
+    /// `input_preview` holds the start of the offending input, escaped so it is safe to log.
+    InvalidUtf8 {
+        source: std::string::FromUtf8Error,
+        input_preview: String,
+    },
+
+    /// The `PathFormatConfig` was contradictory, see [`PathFormatConfig::validate`].
+    InvalidConfig(PathFormatConfigError),
+
+    /// Filesystem access failed, e.g. the path to canonicalize does not exist.
+    IoError(std::io::Error),
+
+    /// The path resolved outside of the jail directory, see [`fmt_path_str_jailed`].
+    JailEscape { attempted_path: String },
+
+    /// The path names a hidden file while [`PathFormatConfig::reject_hidden`] is enabled.
+    HiddenNotAllowed { path: String },
+}
+
+impl std::fmt::Display for PathFormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathFormatError::InvalidUtf8 {
+                source,
+                input_preview,
+            } => {
+                write!(
+                    f,
+                    "Invalid UTF-8 after ANSI stripping of `{}`: {}",
+                    input_preview, source
+                )
+            }
+            PathFormatError::InvalidConfig(e) => {
+                write!(f, "Invalid path format config: {}", e)
+            }
+            PathFormatError::IoError(e) => {
+                write!(f, "Filesystem access failed: {}", e)
+            }
+            PathFormatError::JailEscape { attempted_path } => {
+                write!(f, "Path `{}` escapes its jail directory", attempted_path)
+            }
+            PathFormatError::HiddenNotAllowed { path } => {
+                write!(f, "Hidden file `{}` is not allowed", path)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PathFormatError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PathFormatError::InvalidUtf8 { source, .. } => Some(source),
+            PathFormatError::InvalidConfig(e) => Some(e),
+            PathFormatError::IoError(e) => Some(e),
+            PathFormatError::JailEscape { .. } | PathFormatError::HiddenNotAllowed { .. } => None,
+        }
+    }
+}
+
+impl From<std::string::FromUtf8Error> for PathFormatError {
+    fn from(e: std::string::FromUtf8Error) -> Self {
+        PathFormatError::InvalidUtf8 {
+            input_preview: input_preview(&String::from_utf8_lossy(e.as_bytes())),
+            source: e,
+        }
+    }
+}
+
+impl From<std::io::Error> for PathFormatError {
+    fn from(e: std::io::Error) -> Self {
+        PathFormatError::IoError(e)
+    }
+}
+
+impl From<PathFormatConfigError> for PathFormatError {
+    fn from(e: PathFormatConfigError) -> Self {
+        PathFormatError::InvalidConfig(e)
+    }
+}
+
+/// Contradictory combination of [`PathFormatConfig`] options.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PathFormatConfigError {
+    /// Two options were enabled that cannot both apply.
+    ConflictingOptions {
+        first: &'static str,
+        second: &'static str,
+        reason: &'static str,
+    },
+}
+
+impl std::fmt::Display for PathFormatConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathFormatConfigError::ConflictingOptions {
+                first,
+                second,
+                reason,
+            } => write!(
+                f,
+                "Options `{}` and `{}` cannot both be enabled: {}",
+                first, second, reason
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PathFormatConfigError {}
+
+/// Generates valid configs only: `ensure_leading_slash` is never enabled together with
+/// `strip_leading_slash` or `keep_leading_current_dir`, and `canonicalize` stays off
+/// to keep formatting lexical.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for PathFormatConfig {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let strip_leading_slash = u.arbitrary()?;
+        let keep_leading_current_dir = u.arbitrary()?;
+        Ok(Self {
+            strip_bom: u.arbitrary()?,
+            #[cfg(feature = "strip-ansi")]
+            strip_ansi: u.arbitrary()?,
+            strip_file_scheme: u.arbitrary()?,
+            strip_verbatim_prefix: u.arbitrary()?,
+            strip_url_scheme: u.arbitrary()?,
+            strip_url_host: u.arbitrary()?,
+            strip_unfriendly_chars: u.arbitrary()?,
+            trim_component_trailing_dots_spaces: u.arbitrary()?,
+            resolve_parent_dirs: u.arbitrary()?,
+            collapse_cur_dir: u.arbitrary()?,
+            collapse_consecutive_slashes: u.arbitrary()?,
+            preserve_unc_prefix: u.arbitrary()?,
+            escape_backslashes: u.arbitrary()?,
+            extension_case: u.arbitrary()?,
+            normalize_drive_letter: u.arbitrary()?,
+            extension_separator_policy: u.arbitrary()?,
+            trailing_slash: u.arbitrary()?,
+            strip_leading_slash,
+            keep_leading_current_dir,
+            ensure_leading_slash: !strip_leading_slash
+                && !keep_leading_current_dir
+                && u.arbitrary()?,
+            #[cfg(feature = "fs")]
+            canonicalize: false,
+            lowercase: u.arbitrary()?,
+            lowercase_ascii_only: u.arbitrary()?,
+            reject_hidden: u.arbitrary()?,
+            sanitize_reserved_names: u.arbitrary()?,
+            reserved_name_suffix: "_",
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for TrailingSlashPolicy {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.choose(&[
+            TrailingSlashPolicy::Preserve,
+            TrailingSlashPolicy::AlwaysAdd,
+            TrailingSlashPolicy::AlwaysStrip,
+        ])
+        .copied()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ExtensionSeparatorPolicy {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.choose(&[
+            ExtensionSeparatorPolicy::LastDot,
+            ExtensionSeparatorPolicy::FirstDot,
+            ExtensionSeparatorPolicy::None,
+        ])
+        .copied()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DriveCase {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.choose(&[DriveCase::Upper, DriveCase::Lower, DriveCase::Preserve])
+            .copied()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ExtensionCase {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.choose(&[ExtensionCase::Lower, ExtensionCase::Upper])
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fmt_path::{
+        DriveCase, ExtensionCase, ExtensionSeparatorPolicy, NormalizedPath, PathFormatConfig,
+        PathFormatConfigError, PathFormatError, PathFormatExt, PathTransformStep,
+        TrailingSlashPolicy, explain_fmt_path, fmt_path, fmt_path_custom, fmt_path_os,
+        fmt_path_os_custom, fmt_path_str, fmt_path_str_custom, fmt_path_str_jailed,
+        fmt_path_str_validated, is_hidden, is_normalized, normalize_separators, path_extension,
+        paths_equal, resolve_dot_segments, shorten_path, with_extension,
+    };
+
+    #[test]
+    fn test_extension_case() {
+        let lower = PathFormatConfig {
+            extension_case: Some(ExtensionCase::Lower),
+            ..Default::default()
+        };
+        let upper = PathFormatConfig {
+            extension_case: Some(ExtensionCase::Upper),
+            ..Default::default()
+        };
+
+        let test_cases = vec![
+            ("/docs/README.MD", "/docs/README.md", "/docs/README.MD"),
+            ("photo.Jpg", "photo.jpg", "photo.JPG"),
+            (
+                "/a/archive.Tar.GZ",
+                "/a/archive.Tar.gz",
+                "/a/archive.Tar.GZ",
+            ),
+            ("/a.B/file.C.d", "/a.B/file.C.d", "/a.B/file.C.D"),
+            ("/home/.Bashrc", "/home/.Bashrc", "/home/.Bashrc"),
+            ("/dir.EXT/", "/dir.EXT/", "/dir.EXT/"),
+            ("/no_extension", "/no_extension", "/no_extension"),
+        ];
+
+        for (input, expected_lower, expected_upper) in test_cases {
+            assert_eq!(
+                fmt_path_str_custom(input, &lower).unwrap(),
+                expected_lower,
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                fmt_path_str_custom(input, &upper).unwrap(),
+                expected_upper,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_trailing_slash_policy() {
+        let test_cases = vec![
+            (
+                "/home/user/dir/",
+                "/home/user/dir/",
+                "/home/user/dir/",
+                "/home/user/dir",
+            ),
+            (
+                "/home/user/dir",
+                "/home/user/dir",
+                "/home/user/dir/",
+                "/home/user/dir",
+            ),
+            (
+                "C:\\Users\\dir\\",
+                "C:/Users/dir/",
+                "C:/Users/dir/",
+                "C:/Users/dir",
+            ),
+            ("./a/b.txt", "a/b.txt", "a/b.txt/", "a/b.txt"),
+            ("/", "/", "/", "/"),
+            ("//", "/", "/", "/"),
+            ("C:\\", "C:/", "C:/", "C:/"),
+            ("C:/..", "C:/", "C:/", "C:/"),
+            (".", ".", "", "."),
+            ("a/..", ".", "", "."),
+            ("a/../", "", "", "."),
+            ("./", "", "", "."),
+            ("", "", "", ""),
+        ];
+
+        for (input, preserve, always_add, always_strip) in test_cases {
+            for (policy, expected) in [
+                (TrailingSlashPolicy::Preserve, preserve),
+                (TrailingSlashPolicy::AlwaysAdd, always_add),
+                (TrailingSlashPolicy::AlwaysStrip, always_strip),
+            ] {
+                let config = PathFormatConfig {
+                    trailing_slash: policy,
+                    ..Default::default()
+                };
+                assert_eq!(
+                    fmt_path_str_custom(input, &config).unwrap(),
+                    expected,
+                    "Failed for input: '{}' with {:?}",
+                    input,
+                    policy
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_explain_fmt_path() {
+        let config = PathFormatConfig {
+            extension_case: Some(ExtensionCase::Lower),
+            trailing_slash: TrailingSlashPolicy::AlwaysStrip,
+            ..Default::default()
+        };
+
+        let (result, steps) = explain_fmt_path("C:\\Users\\\\me/x/../Fi*le.TXT/", &config).unwrap();
+        assert_eq!(result, "C:/Users/me/File.txt");
+        assert_eq!(
+            steps,
+            vec![
+                PathTransformStep::EscapedBackslashes,
+                PathTransformStep::StrippedUnfriendlyChars,
+                PathTransformStep::CollapsedSlashes,
+                PathTransformStep::ResolvedParent {
+                    from: "C:/Users/me/x/../File.TXT/".to_string(),
+                    to: "C:/Users/me/File.TXT/".to_string(),
+                },
+                PathTransformStep::StrippedTrailingSlash,
+                PathTransformStep::FoldedExtensionCase {
+                    from: "C:/Users/me/File.TXT".to_string(),
+                    to: "C:/Users/me/File.txt".to_string(),
+                },
+            ]
+        );
+
+        let (result, steps) = explain_fmt_path("/already/clean", &config).unwrap();
+        assert_eq!(result, "/already/clean");
+        assert!(steps.is_empty());
+    }
+
+    #[test]
+    fn test_leading_slash() {
+        let strip = PathFormatConfig {
+            strip_leading_slash: true,
+            ..Default::default()
+        };
+        let ensure = PathFormatConfig {
+            ensure_leading_slash: true,
+            ..Default::default()
+        };
+
+        let test_cases = vec![
+            (
+                "/home/user/file.txt",
+                "home/user/file.txt",
+                "/home/user/file.txt",
+            ),
+            ("home/user/dir/", "home/user/dir/", "/home/user/dir/"),
+            ("//server/share", "server/share", "//server/share"),
+            ("/", "", "/"),
+            ("./", "", ""),
+            (".", ".", "/"),
+            ("../a/", "../a/", "../a/"),
+            ("..", "..", ".."),
+            ("./../a", "../a", "../a"),
+        ];
+
+        for (input, expected_strip, expected_ensure) in test_cases {
+            assert_eq!(fmt_path_str_custom(input, &strip).unwrap(), expected_strip);
+            assert_eq!(
+                fmt_path_str_custom(input, &ensure).unwrap(),
+                expected_ensure
+            );
+        }
+
+        let both = PathFormatConfig {
+            strip_leading_slash: true,
+            ensure_leading_slash: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            fmt_path_str_custom("/a", &both),
+            Err(PathFormatError::InvalidConfig(
+                PathFormatConfigError::ConflictingOptions { .. }
+            ))
+        ));
+    }
+
+    #[cfg(all(feature = "fs", unix))]
+    #[test]
+    fn test_canonicalize() {
+        let root =
+            std::env::temp_dir().join(format!("just_fmt_canonicalize_{}", std::process::id()));
+        let target = root.join("target");
+        std::fs::create_dir_all(&target).unwrap();
+        std::os::unix::fs::symlink(&target, root.join("link")).unwrap();
+
+        let config = PathFormatConfig {
+            canonicalize: true,
+            ..Default::default()
+        };
+        let root_str = std::fs::canonicalize(&root).unwrap().display().to_string();
+
+        let linked = format!("{}/link/../link/", root_str);
+        assert_eq!(
+            fmt_path_str_custom(linked, &config).unwrap(),
+            format!("{}/target/", root_str)
+        );
+
+        let missing = format!("{}/missing", root_str);
+        assert!(matches!(
+            fmt_path_str_custom(missing, &config),
+            Err(PathFormatError::IoError(_))
+        ));
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_is_normalized() {
+        let config = PathFormatConfig::default();
+
+        for clean in ["/home/user/file.txt", "a/b/", "C:/Users/test", "/"] {
+            assert!(is_normalized(clean, &config), "Expected clean: '{}'", clean);
+        }
+        for dirty in [
+            "C:\\Users\\test",
+            "/home//user",
+            "/a/b/../c",
+            "./a",
+            "a/./b",
+            "/what?.txt",
+            "./",
+        ] {
+            assert!(
+                !is_normalized(dirty, &config),
+                "Expected dirty: '{}'",
+                dirty
+            );
+        }
+    }
+
+    #[test]
+    fn test_lowercase() {
+        let lowercase = PathFormatConfig {
+            lowercase: true,
+            ..Default::default()
+        };
+        let ascii_only = PathFormatConfig {
+            lowercase: true,
+            lowercase_ascii_only: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            fmt_path_str_custom("C:/Users/Test", &lowercase).unwrap(),
+            "c:/users/test"
+        );
+        assert_eq!(
+            fmt_path_str_custom("C:\\Users\\ÄRGER\\", &lowercase).unwrap(),
+            "c:/users/ärger/"
+        );
+        assert_eq!(
+            fmt_path_str_custom("C:\\Users\\ÄRGER\\", &ascii_only).unwrap(),
+            "c:/users/Ärger/"
+        );
+
+        let upper_extension = PathFormatConfig {
+            lowercase: true,
+            extension_case: Some(ExtensionCase::Upper),
+            ..Default::default()
+        };
+        assert_eq!(
+            fmt_path_str_custom("/Docs/Readme.md", &upper_extension).unwrap(),
+            "/docs/readme.MD"
+        );
+    }
+
+    #[test]
+    fn test_paths_equal() {
+        let default = PathFormatConfig::default();
+        let ignore_case = PathFormatConfig {
+            lowercase: true,
+            ..Default::default()
+        };
+        let ignore_slash = PathFormatConfig {
+            trailing_slash: TrailingSlashPolicy::AlwaysStrip,
+            ..Default::default()
+        };
+        let ignore_both = PathFormatConfig {
+            lowercase: true,
+            trailing_slash: TrailingSlashPolicy::AlwaysStrip,
+            ..Default::default()
+        };
+
+        let test_cases = vec![
+            // (a, b, default, ignore_case, ignore_slash, ignore_both)
+            ("/a/b", "/a/b", true, true, true, true),
+            ("/A/B/", "/a/b", false, false, false, true),
+            ("/A/B", "/a/b", false, true, false, true),
+            ("/a/b/", "/a/b", false, false, true, true),
+            ("/a//x/../b", "/a/b", true, true, true, true),
+            ("/a/b", "/a/c", false, false, false, false),
+        ];
+
+        for (a, b, expect_default, expect_case, expect_slash, expect_both) in test_cases {
+            assert_eq!(paths_equal(a, b, &default).unwrap(), expect_default);
+            assert_eq!(paths_equal(a, b, &ignore_case).unwrap(), expect_case);
+            assert_eq!(paths_equal(a, b, &ignore_slash).unwrap(), expect_slash);
+            assert_eq!(paths_equal(a, b, &ignore_both).unwrap(), expect_both);
+            assert_eq!(
+                paths_equal(b, a, &ignore_both).unwrap(),
+                expect_both,
+                "Failed for input: '{}'",
+                a
+            );
+        }
+
+        let conflicting = PathFormatConfig {
+            strip_leading_slash: true,
+            ensure_leading_slash: true,
+            ..Default::default()
+        };
+        assert!(paths_equal("/a", "/a", &conflicting).is_err());
+    }
+
+    #[test]
+    fn test_strip_file_scheme() {
+        let config = PathFormatConfig::default();
+        let test_cases = vec![
+            ("file:///home/user/doc.txt", "/home/user/doc.txt"),
+            ("file:///C:/Users/test/doc.txt", "C:/Users/test/doc.txt"),
+            ("file://localhost/etc/hosts", "/etc/hosts"),
+            ("file://server/share/x", "//server/share/x"),
+            ("file://Server/C:/x", "//Server/C:/x"),
+            ("FILE:///home/user/", "/home/user/"),
+            ("file:///C:\\Users\\test", "C:/Users/test"),
+            ("/home/file://x", "/home/file:/x"),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                fmt_path_str_custom(input, &config).unwrap(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let (_, steps) = explain_fmt_path("file:///home", &config).unwrap();
+        assert_eq!(steps, vec![PathTransformStep::StrippedFileScheme]);
+
+        let keep = PathFormatConfig {
+            strip_file_scheme: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            fmt_path_str_custom("file:///home/user", &keep).unwrap(),
+            "file:/home/user"
+        );
+    }
+
+    #[test]
+    fn test_current_dir_before_parent_dir() {
+        let config = PathFormatConfig::default();
+        let test_cases = vec![
+            ("./a/../b", "b"),
+            ("./a/./../b", "b"),
+            ("a/./../b", "b"),
+            ("./a/b/.././c", "a/c"),
+            ("./a/../b/", "b/"),
+            ("/./a/../b", "/b"),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                fmt_path_str_custom(input, &config).unwrap(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_resolve_dot_segments() {
+        let test_cases = vec![
+            ("a/b/../c", "a/c"),
+            ("../x", "../x"),
+            ("a/./b", "a/b"),
+            ("../../x/../y", "../../y"),
+            ("a/../../b", "../b"),
+            ("a/..", "."),
+            ("./", "."),
+            ("", "."),
+            ("a/b/", "a/b/"),
+            ("a//b/../c", "a//c"),
+            ("/../etc", "/etc"),
+            ("/a/../../b", "/b"),
+            ("/", "/"),
+            ("/a/..", "/"),
+            ("C:/a/../b", "C:/b"),
+            ("C:/..", "C:/"),
+            ("C:", "C:"),
+            ("a\\b/../c", "c"),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                resolve_dot_segments(input),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_shorten_path() {
+        let config = PathFormatConfig::default();
+        let test_cases = vec![
+            ("/home/user/project/src/main.rs", 2, ".../src/main.rs"),
+            (
+                "/home/user/project/src/main.rs",
+                5,
+                "/home/user/project/src/main.rs",
+            ),
+            (
+                "/home/user/project/src/main.rs",
+                4,
+                ".../user/project/src/main.rs",
+            ),
+            ("C:\\Users\\test\\docs\\", 2, ".../test/docs/"),
+            ("a//b/../c/d.txt", 3, "a/c/d.txt"),
+            ("src/main.rs", 2, "src/main.rs"),
+            ("/home/user", 0, "..."),
+            ("", 2, ""),
+        ];
+
+        for (input, keep, expected) in test_cases {
+            assert_eq!(
+                shorten_path(input, keep, &config).unwrap(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(PathFormatConfig::default().validate(), Ok(()));
+
+        let both = PathFormatConfig {
+            strip_leading_slash: true,
+            ensure_leading_slash: true,
+            ..Default::default()
+        };
+        let err = both.validate().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Options `strip_leading_slash` and `ensure_leading_slash` cannot both be enabled: \
+             a leading slash cannot be both removed and required"
+        );
+
+        let path_err = explain_fmt_path("/a", &both).unwrap_err();
+        assert!(matches!(path_err, PathFormatError::InvalidConfig(e) if e == err));
+        assert!(!is_normalized("/a", &both));
+    }
+
+    #[test]
+    fn test_path_extension() {
+        let test_cases = vec![
+            ("a/b.txt", Some("txt")),
+            (".gitignore", None),
+            ("a/.gitignore", None),
+            ("a/.config.toml", Some("toml")),
+            ("a.tar.gz", Some("gz")),
+            ("dir/", None),
+            ("dir.d/", None),
+            ("a.b/file", None),
+            ("file.", None),
+            ("", None),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                path_extension(input),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_extension() {
+        let config = PathFormatConfig::default();
+        let test_cases = vec![
+            ("a/b.txt", "md", "a/b.md"),
+            ("a\\b.txt", ".md", "a/b.md"),
+            (".gitignore", "bak", ".gitignore.bak"),
+            ("a.tar.gz", "zst", "a.tar.zst"),
+            ("a.tar.gz", "", "a.tar"),
+            ("a/file", "rs", "a/file.rs"),
+            ("a/file.", "rs", "a/file.rs"),
+            ("dir/", "txt", "dir/"),
+            ("a/..", "txt", "."),
+        ];
+
+        for (input, extension, expected) in test_cases {
+            assert_eq!(
+                with_extension(input, extension, &config).unwrap(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_strip_bom() {
+        let config = PathFormatConfig::default();
+        let test_cases = vec![
+            ("\u{FEFF}/home/user/file.txt", "/home/user/file.txt"),
+            ("\u{FEFF}C:\\Users\\test\\", "C:/Users/test/"),
+            ("\u{FEFF}\u{FEFF}a", "a"),
+            ("/home/\u{FEFF}user", "/home/\u{FEFF}user"),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                fmt_path_str_custom(input, &config).unwrap(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let (_, steps) = explain_fmt_path("\u{FEFF}a/b", &config).unwrap();
+        assert_eq!(steps, vec![PathTransformStep::StrippedBom]);
+
+        let keep = PathFormatConfig {
+            strip_bom: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            fmt_path_str_custom("\u{FEFF}/home/user", &keep).unwrap(),
+            "\u{FEFF}/home/user"
+        );
+    }
+
+    #[test]
+    fn test_extension_separator_policy() {
+        let test_cases = vec![
+            (
+                ExtensionSeparatorPolicy::LastDot,
+                "dir/File.TXT.BAK",
+                "dir/File.TXT.bak",
+                "dir/File.TXT.old",
+            ),
+            (
+                ExtensionSeparatorPolicy::FirstDot,
+                "dir/File.TXT.BAK",
+                "dir/File.txt.bak",
+                "dir/File.old",
+            ),
+            (
+                ExtensionSeparatorPolicy::None,
+                "dir/File.TXT.BAK",
+                "dir/File.TXT.BAK",
+                "dir/File.TXT.BAK.old",
+            ),
+            (
+                ExtensionSeparatorPolicy::FirstDot,
+                ".Config.TOML.BAK",
+                ".Config.toml.bak",
+                ".Config.old",
+            ),
+            (
+                ExtensionSeparatorPolicy::FirstDot,
+                ".BASHRC",
+                ".BASHRC",
+                ".BASHRC.old",
+            ),
+        ];
+
+        for (policy, input, folded, replaced) in test_cases {
+            let config = PathFormatConfig {
+                extension_case: Some(ExtensionCase::Lower),
+                extension_separator_policy: policy,
+                ..Default::default()
+            };
+            assert_eq!(
+                fmt_path_str_custom(input, &config).unwrap(),
+                folded,
+                "Failed for input: '{}'",
+                input
+            );
+
+            let config = PathFormatConfig {
+                extension_separator_policy: policy,
+                ..Default::default()
+            };
+            assert_eq!(
+                with_extension(input, "old", &config).unwrap(),
+                replaced,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_parent_dir_stops_at_root() {
+        let config = PathFormatConfig::default();
+        let test_cases = vec![
+            ("/../etc", "/etc"),
+            ("/a/../../b", "/b"),
+            ("/../../..", "/"),
+            ("", ""),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                fmt_path_str_custom(input, &config).unwrap(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_fmt_path_str_jailed() {
+        let accepted = vec![
+            ("index.html", "/srv/www", "/srv/www/index.html"),
+            ("css/../img/logo.png", "/srv/www/", "/srv/www/img/logo.png"),
+            ("/etc/passwd", "/srv/www", "/srv/www/etc/passwd"),
+            ("a/..", "/srv/www", "/srv/www"),
+            ("", "/srv/www", "/srv/www"),
+            ("docs\\guide.md", "C:\\site", "C:/site/docs/guide.md"),
+            ("a/../b", "www", "www/b"),
+            ("../etc", "/", "/etc"),
+            ("a/../b", "", "b"),
+        ];
+        for (path, jail, expected) in accepted {
+            assert_eq!(
+                fmt_path_str_jailed(path, jail).unwrap(),
+                expected,
+                "Failed for input: '{}'",
+                path
+            );
+        }
+
+        let rejected = vec![
+            ("..", "/srv/www", "/srv"),
+            ("../../etc/passwd", "/srv/www", "/etc/passwd"),
+            ("a/../../www2/x", "/srv/www", "/srv/www2/x"),
+            ("../b", "www", "b"),
+            ("../b", "", "../b"),
+            ("../b", "./", "../b"),
+        ];
+        for (path, jail, attempted) in rejected {
+            match fmt_path_str_jailed(path, jail) {
+                Err(PathFormatError::JailEscape { attempted_path }) => {
+                    assert_eq!(attempted_path, attempted, "Failed for input: '{}'", path)
+                }
+                other => panic!("Failed for input: '{}': {:?}", path, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_hidden() {
+        let test_cases = vec![
+            ("dir/.secret", true),
+            (".gitignore", true),
+            ("dir/.config/", true),
+            ("C:\\Users\\.ssh", true),
+            ("a/b.txt", false),
+            ("..", false),
+            (".", false),
+            ("a/..", false),
+            (".hidden/visible", false),
+            ("", false),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(is_hidden(input), expected, "Failed for input: '{}'", input);
+        }
+    }
+
+    #[test]
+    fn test_reject_hidden() {
+        let config = PathFormatConfig {
+            reject_hidden: true,
+            ..Default::default()
+        };
+
+        assert_eq!(fmt_path_str_custom("a/b.txt", &config).unwrap(), "a/b.txt");
+        assert_eq!(fmt_path_str_custom("a/./b/..", &config).unwrap(), "a");
+        assert!(matches!(
+            fmt_path_str_custom("dir/.secret", &config),
+            Err(PathFormatError::HiddenNotAllowed { path }) if path == "dir/.secret"
+        ));
+        assert!(matches!(
+            fmt_path_str_custom("docs/../.env", &config),
+            Err(PathFormatError::HiddenNotAllowed { path }) if path == ".env"
+        ));
+        assert_eq!(
+            fmt_path_str_custom("dir/.secret", &PathFormatConfig::default()).unwrap(),
+            "dir/.secret"
+        );
+    }
+
+    #[test]
+    fn test_leading_parent_dir_preserved() {
+        let config = PathFormatConfig::default();
+        let test_cases = vec![
+            ("../config/app.toml", "../config/app.toml"),
+            ("../../x", "../../x"),
+            ("../a/b/../c", "../a/c"),
+            ("../a/../../b", "../../b"),
+            ("./../x", "../x"),
+            ("..", ".."),
+            ("../", "../"),
+            ("a/../../x", "../x"),
+            ("a/b/../c", "a/c"),
+            ("/../x", "/x"),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                fmt_path_str_custom(input, &config).unwrap(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_rooted_paths_stay_rooted() {
+        let config = PathFormatConfig::default();
+        let test_cases = vec![
+            ("/../etc/passwd", "/etc/passwd"),
+            ("/..", "/"),
+            ("/a/b/../../../../c", "/c"),
+            ("/../../../a/../b/", "/b/"),
+            ("C:/..", "C:/"),
+            ("C:/../Windows", "C:/Windows"),
+            ("C:\\a\\..\\..\\..\\b", "C:/b"),
+            ("C:/a/../../", "C:/"),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                fmt_path_str_custom(input, &config).unwrap(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_trailing_separator_detection() {
+        let config = PathFormatConfig::default();
+        let test_cases = vec![
+            ("C:\\Users\\dir\\", "C:/Users/dir/"),
+            ("dir\\", "dir/"),
+            ("dir/\\", "dir/"),
+            ("dir\\/", "dir/"),
+            ("dir", "dir"),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                fmt_path_str_custom(input, &config).unwrap(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        #[cfg(feature = "strip-ansi")]
+        for (input, expected) in [
+            ("dir\\\x1b[0m", "dir/"),
+            ("\x1b[31mdir/\x1b[0m", "dir/"),
+            ("dir\x1b[0m", "dir"),
+        ] {
+            assert_eq!(
+                fmt_path_str_custom(input, &config).unwrap(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_keep_leading_current_dir() {
+        let config = PathFormatConfig {
+            keep_leading_current_dir: true,
+            ..Default::default()
+        };
+        let test_cases = vec![
+            ("./a/../b", "./b"),
+            ("././x", "./x"),
+            ("./", "./"),
+            ("./home/file.txt", "./home/file.txt"),
+            (".\\dir\\", "./dir/"),
+            ("./a/..", "./"),
+            ("./../x", "../x"),
+            ("a/b", "a/b"),
+            ("/a/./b", "/a/b"),
+        ];
+        for (input, expected) in test_cases {
+            assert_eq!(
+                fmt_path_str_custom(input, &config).unwrap(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        for (policy, input, expected) in [
+            (TrailingSlashPolicy::AlwaysStrip, "./dir/", "./dir"),
+            (TrailingSlashPolicy::AlwaysStrip, "./", "."),
+            (TrailingSlashPolicy::AlwaysAdd, "./dir", "./dir/"),
+            (TrailingSlashPolicy::AlwaysAdd, "./", "./"),
+        ] {
+            let config = PathFormatConfig {
+                trailing_slash: policy,
+                ..config
+            };
+            assert_eq!(
+                fmt_path_str_custom(input, &config).unwrap(),
+                expected,
+                "Failed for input: '{}' with {:?}",
+                input,
+                policy
+            );
+        }
+
+        assert_eq!(
+            fmt_path_str_custom("./a/../b", &PathFormatConfig::default()).unwrap(),
+            "b"
+        );
+        let conflicting = PathFormatConfig {
+            ensure_leading_slash: true,
+            ..config
+        };
+        assert!(matches!(
+            conflicting.validate(),
+            Err(PathFormatConfigError::ConflictingOptions {
+                first: "keep_leading_current_dir",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_fmt_path_os() {
+        use std::ffi::{OsStr, OsString};
+
+        let test_cases = vec![
+            ("C:\\Users\\\\test", "C:/Users/test"),
+            ("/home/user/../file.txt", "/home/file.txt"),
+            ("./dir/", "dir/"),
+        ];
+        for (input, expected) in test_cases {
+            assert_eq!(
+                fmt_path_os(OsStr::new(input)).unwrap(),
+                OsString::from(expected),
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                fmt_path_os(OsString::from(input)).unwrap(),
+                OsString::from(expected),
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let config = PathFormatConfig {
+            lowercase: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            fmt_path_os_custom(OsStr::new("/Home/User"), &config).unwrap(),
+            OsString::from("/home/user")
+        );
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStrExt;
+            let invalid = OsStr::from_bytes(b"/home/\xffuser");
+            assert_eq!(
+                fmt_path_os(invalid).unwrap(),
+                OsString::from("/home/\u{FFFD}user")
+            );
+        }
+    }
+
+    #[test]
+    fn test_collapse_cur_dir() {
+        // (input, neither, collapse only, resolve only, both)
+        let test_cases = vec![
+            ("a/./b/./c", "a/./b/./c", "a/b/c", "a/./b/./c", "a/b/c"),
+            ("a/./b/../c", "a/./b/../c", "a/b/../c", "a/./c", "a/c"),
+            ("a/./..", "a/./..", "a/..", ".", "."),
+            ("/./a/b/..", "/./a/b/..", "/a/b/..", "/./a", "/a"),
+            ("./a/", "./a/", "a/", "./a/", "a/"),
+        ];
+
+        for (input, neither, collapse_only, resolve_only, both) in test_cases {
+            for (resolve_parent_dirs, collapse_cur_dir, expected) in [
+                (false, false, neither),
+                (false, true, collapse_only),
+                (true, false, resolve_only),
+                (true, true, both),
+            ] {
+                let config = PathFormatConfig {
+                    resolve_parent_dirs,
+                    collapse_cur_dir,
+                    ..Default::default()
+                };
+                assert_eq!(
+                    fmt_path_str_custom(input, &config).unwrap(),
+                    expected,
+                    "Failed for input: '{}' with resolve_parent_dirs={} collapse_cur_dir={}",
+                    input,
+                    resolve_parent_dirs,
+                    collapse_cur_dir
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_escape_backslashes_respected() {
+        let escape = PathFormatConfig::default();
+        let keep = PathFormatConfig {
+            escape_backslashes: false,
+            ..Default::default()
+        };
+
+        // (input, escaped, kept)
+        let test_cases = vec![
+            (
+                "/home/user/file\\name.txt",
+                "/home/user/file/name.txt",
+                "/home/user/file\\name.txt",
+            ),
+            ("/home/a\\b/../c", "/home/a/c", "/home/c"),
+            ("C:\\Users\\test", "C:/Users/test", "C:\\Users\\test"),
+            ("C:\\Users\\..\\test\\", "C:/test/", "C:\\Users\\..\\test\\"),
+        ];
+
+        for (input, escaped, kept) in test_cases {
+            assert_eq!(
+                fmt_path_str_custom(input, &escape).unwrap(),
+                escaped,
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                fmt_path_str_custom(input, &keep).unwrap(),
+                kept,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_resolution_flag_matrix() {
+        // (resolve_parent_dirs, collapse_cur_dir, collapse_consecutive_slashes)
+        let test_cases = vec![
+            ((true, true, true), "a//b/./../c", "a/c"),
+            ((true, true, false), "a//b/./../c", "a//c"),
+            ((true, false, true), "a//b/./../c", "a/./c"),
+            ((true, false, false), "a//b/./../c", "a//./c"),
+            ((false, true, true), "a//b/./../c", "a/b/../c"),
+            ((false, true, false), "a//b/./../c", "a//b/../c"),
+            ((false, false, true), "a//b/./../c", "a/b/./../c"),
+            ((false, false, false), "a//b/./../c", "a//b/./../c"),
+            ((true, true, false), "./a//b/", "a//b/"),
+            ((true, true, false), "/..//a/../b", "//b"),
+            ((true, false, false), "./a/../b", "./b"),
+            ((true, true, false), "a//../b", "b"),
+            ((true, true, false), "a/b//../c", "a/c"),
+        ];
+
+        for (
+            (resolve_parent_dirs, collapse_cur_dir, collapse_consecutive_slashes),
+            input,
+            expected,
+        ) in test_cases
+        {
+            let config = PathFormatConfig {
+                resolve_parent_dirs,
+                collapse_cur_dir,
+                collapse_consecutive_slashes,
+                ..Default::default()
+            };
+            assert_eq!(
+                fmt_path_str_custom(input, &config).unwrap(),
+                expected,
+                "Failed for input: '{}' with {:?}",
+                input,
+                (
+                    resolve_parent_dirs,
+                    collapse_cur_dir,
+                    collapse_consecutive_slashes
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_drive_resolution_is_platform_independent() {
+        let test_cases = vec![
+            ("C:/a/../b", "C:/b"),
+            ("C:\\a\\..\\b", "C:/b"),
+            ("C:/a/../../b", "C:/b"),
+            ("C:/..", "C:/"),
+            ("c:/Users/../Windows/", "c:/Windows/"),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                fmt_path_str(input).unwrap(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                resolve_dot_segments(input.replace('\\', "/").as_str()),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_path_format_ext() {
+        let config = PathFormatConfig {
+            trailing_slash: TrailingSlashPolicy::AlwaysStrip,
+            ..Default::default()
+        };
+        let test_cases = vec![
+            ("C:\\a\\\\b", "C:/a/b"),
+            ("./home//path/", "home/path/"),
+            ("a/b/../c", "a/c"),
+            ("", ""),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                input.fmt_path().unwrap(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                input.to_string().fmt_path().unwrap(),
+                fmt_path_str(input).unwrap(),
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                std::path::Path::new(input)
+                    .fmt_path_custom(&config)
+                    .unwrap(),
+                fmt_path_custom(input, &config).unwrap(),
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                std::path::PathBuf::from(input).fmt_path().unwrap(),
+                fmt_path(input).unwrap(),
+                "Failed for input: '{}'",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_unc_paths() {
+        let test_cases = vec![
+            (
+                "\\\\fileserver\\projects\\data",
+                "//fileserver/projects/data",
+            ),
+            ("//fileserver/projects/data", "//fileserver/projects/data"),
+            ("\\\\server\\share\\..\\other", "//server/share/other"),
+            ("\\\\server\\share\\a\\..\\..\\..\\b", "//server/share/b"),
+            ("//server//share///a", "//server/share/a"),
+            ("\\\\server\\share\\", "//server/share/"),
+            ("\\\\server\\share", "//server/share"),
+            ("//server/share/a/..", "//server/share/"),
+            ("//server", "//server"),
+            ("///server/share", "/server/share"),
+            ("//./a", "/a"),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                fmt_path_str(input).unwrap(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+            assert!(is_normalized(expected, &PathFormatConfig::default()));
+        }
+
+        let strip = PathFormatConfig {
+            trailing_slash: TrailingSlashPolicy::AlwaysStrip,
+            ..Default::default()
+        };
+        assert_eq!(
+            fmt_path_str_custom("\\\\server\\share\\", &strip).unwrap(),
+            "//server/share"
+        );
+        assert_eq!(
+            fmt_path_str_custom("//server/share/a/..", &strip).unwrap(),
+            "//server/share"
+        );
+
+        let collapse_unc = PathFormatConfig {
+            preserve_unc_prefix: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            fmt_path_str_custom("\\\\server\\share\\..\\other", &collapse_unc).unwrap(),
+            "/server/other"
+        );
+    }
+
+    #[test]
+    fn test_windows_namespace_prefixes() {
+        let strip = PathFormatConfig {
+            strip_verbatim_prefix: true,
+            ..Default::default()
+        };
+        let test_cases = vec![
+            (
+                r"\\?\C:\very\long\..\path",
+                r"\\?\C:\very\long\..\path",
+                "C:/very/path",
+            ),
+            (
+                r"\\?\UNC\server\share\dir\..\file",
+                r"\\?\UNC\server\share\dir\..\file",
+                "//server/share/file",
+            ),
+            (
+                r"\\?\unc\server\share",
+                r"\\?\unc\server\share",
+                "//server/share",
+            ),
+            (r"\\.\PIPE\name", r"\\.\PIPE\name", r"\\.\PIPE\name"),
+            (r"\\.\COM1", r"\\.\COM1", r"\\.\COM1"),
+        ];
+
+        for (input, kept, stripped) in test_cases {
+            assert_eq!(
+                fmt_path_str(input).unwrap(),
+                kept,
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                fmt_path_str_custom(input, &strip).unwrap(),
+                stripped,
+                "Failed for input: '{}'",
+                input
+            );
+            assert!(is_normalized(kept, &PathFormatConfig::default()));
+        }
+
+        let (_, steps) = explain_fmt_path(r"\\?\C:\dir", &strip).unwrap();
+        assert_eq!(
+            steps,
+            vec![
+                PathTransformStep::StrippedVerbatimPrefix,
+                PathTransformStep::EscapedBackslashes,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_namespaced_paths_are_validated() {
+        let config = PathFormatConfig {
+            reject_hidden: true,
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            fmt_path_str_custom(r"\\?\C:\dir\.env", &config),
+            Err(PathFormatError::HiddenNotAllowed { path }) if path == r"\\?\C:\dir\.env"
+        ));
+        assert!(matches!(
+            fmt_path_str_custom("C:/dir/.env", &config),
+            Err(PathFormatError::HiddenNotAllowed { .. })
+        ));
+        assert_eq!(
+            fmt_path_str_custom(r"\\?\C:\dir\file", &config).unwrap(),
+            r"\\?\C:\dir\file"
+        );
+
+        assert_eq!(
+            fmt_path_str_jailed("a/b.txt", r"\\?\C:\srv").unwrap(),
+            r"\\?\C:\srv\a\b.txt"
+        );
+        assert!(matches!(
+            fmt_path_str_jailed(r"a\..\..\..\x", r"\\?\C:\srv"),
+            Err(PathFormatError::JailEscape { .. })
+        ));
+    }
+
+    #[test]
+    fn test_strip_url() {
+        let scheme = PathFormatConfig {
+            strip_url_scheme: true,
+            ..Default::default()
+        };
+        let host = PathFormatConfig {
+            strip_url_scheme: true,
+            strip_url_host: true,
+            ..Default::default()
+        };
+        let test_cases = vec![
+            (
+                "https://example.com/docs/api/v2/endpoint",
+                "example.com/docs/api/v2/endpoint",
+                "/docs/api/v2/endpoint",
+            ),
+            (
+                "https://example.com/docs/api",
+                "example.com/docs/api",
+                "/docs/api",
+            ),
+            (
+                "http://user@example.com:8080/a//b/../c/?q=1#top",
+                "user@example.com:8080/a/c/",
+                "/a/c/",
+            ),
+            ("https://example.com", "example.com", "/"),
+            ("https://example.com?q=1", "example.com", "/"),
+            ("git+ssh://host/repo.git", "host/repo.git", "/repo.git"),
+            ("C://dir/file", "C:/dir/file", "C:/dir/file"),
+            ("/plain/path", "/plain/path", "/plain/path"),
+        ];
+
+        for (input, expected_scheme, expected_host) in test_cases {
+            assert_eq!(
+                fmt_path_str_custom(input, &scheme).unwrap(),
+                expected_scheme,
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                fmt_path_str_custom(input, &host).unwrap(),
+                expected_host,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let host_only = PathFormatConfig {
+            strip_url_host: true,
+            ..Default::default()
+        };
+        let (result, steps) = explain_fmt_path("https://example.com/docs?q=1", &host_only).unwrap();
+        assert_eq!(result, "/docs");
+        assert_eq!(
+            steps,
+            vec![
+                PathTransformStep::StrippedUrlScheme,
+                PathTransformStep::StrippedUrlHost,
+                PathTransformStep::StrippedUrlQuery,
+            ]
+        );
+
+        // Disabled by default
+        assert_eq!(
+            fmt_path_str("https://example.com/docs").unwrap(),
+            "https:/example.com/docs"
+        );
+    }
+
+    #[test]
+    fn test_drive_letters() {
+        let upper = PathFormatConfig {
+            normalize_drive_letter: Some(DriveCase::Upper),
+            ..Default::default()
+        };
+        let lower = PathFormatConfig {
+            normalize_drive_letter: Some(DriveCase::Lower),
+            ..Default::default()
+        };
+        let test_cases = vec![
+            ("c:\\users\\me", "c:/users/me", "C:/users/me", "c:/users/me"),
+            ("C:\\Users\\me", "C:/Users/me", "C:/Users/me", "c:/Users/me"),
+            ("C:foo\\bar", "C:foo/bar", "C:foo/bar", "c:foo/bar"),
+            ("c:foo\\..\\bar", "c:bar", "C:bar", "c:bar"),
+            ("C:..\\bar", "C:../bar", "C:../bar", "c:../bar"),
+            ("C:foo\\..", "C:.", "C:.", "c:."),
+            ("dir/a:b/../c", "dir/c", "dir/c", "dir/c"),
+            ("ab:c/d", "ab:c/d", "ab:c/d", "ab:c/d"),
+        ];
+
+        for (input, preserved, uppercased, lowercased) in test_cases {
+            assert_eq!(
+                fmt_path_str(input).unwrap(),
+                preserved,
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                fmt_path_str_custom(input, &upper).unwrap(),
+                uppercased,
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                fmt_path_str_custom(input, &lower).unwrap(),
+                lowercased,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let ensure = PathFormatConfig {
+            ensure_leading_slash: true,
+            ..Default::default()
+        };
+        assert_eq!(fmt_path_str_custom("C:foo", &ensure).unwrap(), "C:foo");
+        assert_eq!(fmt_path_str_custom("C:\\foo", &ensure).unwrap(), "C:/foo");
+    }
+
+    #[test]
+    fn test_normalized_path() {
+        let test_cases = vec![
+            ("/home/user/../docs/", "/home/docs/"),
+            ("C:\\Users\\\\me", "C:/Users/me"),
+            ("./a/./b", "a/b"),
+            ("", ""),
+        ];
+
+        for (input, expected) in test_cases {
+            let from_str = NormalizedPath::try_from(input).unwrap();
+            assert_eq!(from_str.as_str(), expected, "Failed for input: '{}'", input);
+            assert_eq!(
+                NormalizedPath::try_from(input.to_string()).unwrap(),
+                from_str,
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                NormalizedPath::try_from(std::path::Path::new(input)).unwrap(),
+                from_str,
+                "Failed for input: '{}'",
+                input
+            );
+            assert_eq!(
+                NormalizedPath::try_from(std::path::PathBuf::from(input)).unwrap(),
+                from_str,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let path = NormalizedPath::try_from("a//b").unwrap();
+        assert_eq!(path.to_string(), "a/b");
+        assert_eq!(path.as_path(), std::path::Path::new("a/b"));
+        assert_eq!(String::from(path.clone()), "a/b");
+        assert_eq!(path.into_inner(), "a/b");
+    }
+
+    #[test]
+    fn test_sanitize_reserved_names() {
+        let config = PathFormatConfig {
+            sanitize_reserved_names: true,
+            ..Default::default()
+        };
+        let test_cases = vec![
+            ("logs/nul.log", "logs/nul_.log"),
+            ("CON", "CON_"),
+            ("dir/Prn.txt", "dir/Prn_.txt"),
+            ("aux/data", "aux_/data"),
+            ("com1/COM9.tar.gz", "com1_/COM9_.tar.gz"),
+            ("lpt1/LpT9/", "lpt1_/LpT9_/"),
+            (
+                "console/nullable/com10/lpt.txt",
+                "console/nullable/com10/lpt.txt",
+            ),
+            ("C:/Users/con", "C:/Users/con_"),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                fmt_path_str_custom(input, &config).unwrap(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let tilde = PathFormatConfig {
+            reserved_name_suffix: "~",
+            ..config
+        };
+        assert_eq!(
+            fmt_path_str_custom("logs/nul.log", &tilde).unwrap(),
+            "logs/nul~.log"
+        );
+
+        // Disabled by default
+        assert_eq!(fmt_path_str("logs/nul.log").unwrap(), "logs/nul.log");
+    }
+
+    #[test]
+    fn test_fmt_path_str_validated() {
+        let config = PathFormatConfig::default();
+        let no_dot_components = |path: &str| {
+            if path.split('/').any(|component| component.starts_with('.')) {
+                Err(PathFormatError::HiddenNotAllowed {
+                    path: path.to_string(),
+                })
+            } else {
+                Ok(())
+            }
+        };
+
+        let test_cases = vec![
+            ("a/./b", Some("a/b")),
+            ("a/.git/../b", Some("a/b")),
+            ("a/.git/config", None),
+            ("../a", None),
+        ];
+
+        for (input, expected) in test_cases {
+            let result = fmt_path_str_validated(input, &config, no_dot_components);
+            match expected {
+                Some(expected) => {
+                    assert_eq!(result.unwrap(), expected, "Failed for input: '{}'", input)
+                }
+                None => assert!(
+                    matches!(result, Err(PathFormatError::HiddenNotAllowed { .. })),
+                    "Failed for input: '{}'",
+                    input
+                ),
+            }
+        }
+
+        // The validation only sees successfully normalized paths
+        let invalid = PathFormatConfig {
+            strip_leading_slash: true,
+            ensure_leading_slash: true,
+            ..Default::default()
+        };
+        let result = fmt_path_str_validated("a", &invalid, |_| panic!("validated"));
+        assert!(matches!(result, Err(PathFormatError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_invalid_utf8_preview() {
+        let utf8_error = String::from_utf8(b"bad\xff/\x1bpath".to_vec()).unwrap_err();
+        let error = PathFormatError::from(utf8_error);
+
+        match &error {
+            PathFormatError::InvalidUtf8 { input_preview, .. } => {
+                assert_eq!(input_preview, "bad\u{fffd}/\\u{1b}path")
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert!(std::error::Error::source(&error).is_some());
+        assert!(error.to_string().contains("bad\u{fffd}/\\u{1b}path"));
+
+        let long = format!("{}\u{ff}", "a".repeat(100));
+        let error = PathFormatError::from(
+            String::from_utf8(long.into_bytes()[..101].to_vec()).unwrap_err(),
+        );
+        match error {
+            PathFormatError::InvalidUtf8 { input_preview, .. } => {
+                assert_eq!(input_preview, format!("{}...", "a".repeat(64)))
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_trim_component_trailing_dots_spaces() {
+        let config = PathFormatConfig {
+            trim_component_trailing_dots_spaces: true,
+            ..Default::default()
+        };
+        let test_cases = vec![
+            ("a./b /c..", "a/b/c"),
+            ("dir/report. ", "dir/report"),
+            ("a/.../b", "a/b"),
+            ("a/. ./b", "a/b"),
+            ("a/b/...", "a/b"),
+            ("a/b./", "a/b/"),
+            ("a/.../", "a/"),
+            ("./a/../b.", "b"),
+            ("/...", "/"),
+            ("C:\\...", "C:/"),
+            ("...", ""),
+            (".hidden./file", ".hidden/file"),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                fmt_path_str_custom(input, &config).unwrap(),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
+
+        let strip = PathFormatConfig {
+            trailing_slash: TrailingSlashPolicy::AlwaysStrip,
+            ..config
+        };
+        assert_eq!(fmt_path_str_custom("a/b. /", &strip).unwrap(), "a/b");
+
+        // Disabled by default
+        assert_eq!(fmt_path_str("a./b ").unwrap(), "a./b ");
+    }
+
+    #[test]
+    fn test_normalize_separators() {
+        let test_cases = vec![
+            ("a\\\\b//c", "a/b/c"),
+            ("\\\\server\\share\\", "/server/share/"),
+            ("./a/../b*", "./a/../b*"),
+            ("a/\\/b", "a/b"),
+            ("", ""),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                normalize_separators(input),
+                expected,
+                "Failed for input: '{}'",
+                input
+            );
+        }
     }
 }
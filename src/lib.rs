@@ -12,7 +12,7 @@
 /// # Examples
 ///
 /// ```
-/// # use just_fmt::fmt_case::CaseFormatter;
+/// # use just_fmt::fmt_case_style::CaseFormatter;
 /// // Using CaseFormatter
 /// let formatter = CaseFormatter::from("brew_coffee");
 /// assert_eq!(formatter.to_camel_case(), "brewCoffee");
@@ -21,7 +21,7 @@
 /// assert_eq!(formatter.to_kebab_case(), "brew-coffee");
 ///
 /// // Using macros
-/// # use just_fmt::fmt_case::{camel_case, pascal_case, snake_case, kebab_case}
+/// # use just_fmt::{camel_case, pascal_case, snake_case, kebab_case};
 /// assert_eq!(camel_case!("brew coffee"), "brewCoffee");
 /// assert_eq!(pascal_case!("brewCoffee"), "BrewCoffee");
 /// assert_eq!(snake_case!("brew_coffee"), "brew_coffee");
@@ -35,9 +35,10 @@
 /// - Comma `,`
 /// - Dot `.`
 /// - Hyphen `-`
-/// - Space ` `
+/// - Whitespace
 ///
-/// It can also automatically detect case boundaries (e.g., "camel" and "Case" in "camelCase")
+/// It can also automatically detect case boundaries (e.g., "camel" and "Case" in "camelCase").
+/// Slash `/` and colon `:` are optional separators, see `SplitConfig` and `CaseFormatter::from_namespace`
 pub mod fmt_case_style;
 
 /// Normalize an input path string into a canonical, platform‑agnostic form.
@@ -45,4 +46,33 @@ pub mod fmt_case_style;
 /// This function removes ANSI escape sequences, unifies separators to `/`,
 /// collapses duplicate slashes, strips unfriendly characters (`*`, `?`, `"`, `<`, `>`, `|`),
 /// resolves simple `..` components, and preserves a trailing slash when present.
+///
+/// # Invariants
+///
+/// For any input string, [`fmt_path::fmt_path_str`] and [`fmt_path::fmt_path_str_custom`]:
+///
+/// - never panic;
+/// - never return `//` when `collapse_consecutive_slashes` is enabled,
+///   except at the start of a UNC path (`//server/share`);
+/// - return Windows verbatim (`\\?\`) and device (`\\.\`) paths untouched, unless the verbatim
+///   prefix is stripped;
+/// - never let `..` climb past the root of an absolute path;
+/// - are idempotent with the default config: formatting a formatted path returns it unchanged
+///   (inputs containing ANSI escape characters or byte order marks past the first character excepted,
+///   as stripping may expose new ones).
 pub mod fmt_path;
+
+/// Rename an item's identifier to a naming case style at compile time
+///
+/// Requires the `derive` feature.
+#[cfg(feature = "derive")]
+pub use just_fmt_derive::rename_case;
+
+/// Proptest strategies generating values of this crate
+///
+/// The generated values follow the same rules as the `arbitrary::Arbitrary` implementations:
+/// formatters hold non-empty lowercase words and configs always pass validation.
+///
+/// Requires the `arbitrary` feature.
+#[cfg(feature = "arbitrary")]
+pub mod testing;
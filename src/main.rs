@@ -0,0 +1,139 @@
+//! Command line front end for quick conversions from shell scripts.
+//!
+//! ```text
+//! just_fmt --style snake BrewCoffee           # brew_coffee
+//! just_fmt path --strip-trailing 'C:\a\\b\'   # C:/a/b
+//! echo BrewCoffee | just_fmt --style kebab     # brew-coffee
+//! ```
+//!
+//! Every argument is converted and printed on its own line.
+//! Without arguments, every line of stdin is converted instead.
+
+use std::io::BufRead;
+use std::process::ExitCode;
+
+use just_fmt::fmt_case_style::{Case, CaseFormatter};
+use just_fmt::fmt_path::{PathFormatConfig, TrailingSlashPolicy, fmt_path_str_custom};
+
+const USAGE: &str = "\
+Usage:
+    just_fmt --style <STYLE> [INPUT]...
+    just_fmt path [OPTIONS] [INPUT]...
+
+Reads lines from stdin when no INPUT is given.
+
+Styles:
+    camel, pascal, snake, screaming-snake, kebab, screaming-kebab, dot, title, lower, upper
+
+Path options:
+    --strip-trailing    Remove the trailing slash
+    --add-trailing      Always end with a slash
+    --strip-leading     Make absolute paths relative
+    --ensure-leading    Make relative paths absolute
+    --lowercase         Lowercase the whole path
+    --keep-parent       Do not resolve `..` components";
+
+enum Command {
+    Case(Case),
+    Path(PathFormatConfig),
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    let (command, inputs) = match parse_args(&args) {
+        Ok(parsed) => parsed,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let inputs = if inputs.is_empty() {
+        match std::io::stdin().lock().lines().collect() {
+            Ok(lines) => lines,
+            Err(e) => {
+                eprintln!("error: failed to read stdin: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        inputs
+    };
+
+    for input in inputs {
+        match &command {
+            Command::Case(case) => println!("{}", CaseFormatter::from(input).convert(*case)),
+            Command::Path(config) => match fmt_path_str_custom(input.as_str(), config) {
+                Ok(path) => println!("{}", path),
+                Err(e) => {
+                    eprintln!("error: {}: {}", input, e);
+                    return ExitCode::FAILURE;
+                }
+            },
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn parse_args(args: &[String]) -> Result<(Command, Vec<String>), String> {
+    let mut inputs = Vec::new();
+
+    if args.first().is_some_and(|arg| arg == "path") {
+        let mut config = PathFormatConfig::default();
+        for arg in &args[1..] {
+            match arg.as_str() {
+                "--strip-trailing" => config.trailing_slash = TrailingSlashPolicy::AlwaysStrip,
+                "--add-trailing" => config.trailing_slash = TrailingSlashPolicy::AlwaysAdd,
+                "--strip-leading" => config.strip_leading_slash = true,
+                "--ensure-leading" => config.ensure_leading_slash = true,
+                "--lowercase" => config.lowercase = true,
+                "--keep-parent" => config.resolve_parent_dirs = false,
+                option if option.starts_with("--") => {
+                    return Err(format!("unknown path option `{}`", option));
+                }
+                input => inputs.push(input.to_string()),
+            }
+        }
+        config.validate().map_err(|e| e.to_string())?;
+        return Ok((Command::Path(config), inputs));
+    }
+
+    let mut style = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--style" | "-s" => {
+                let name = args.next().ok_or("`--style` needs a value")?;
+                style = Some(parse_style(name)?);
+            }
+            option if option.starts_with("--") => {
+                return Err(format!("unknown option `{}`", option));
+            }
+            input => inputs.push(input.to_string()),
+        }
+    }
+    let style = style.ok_or("missing `--style`")?;
+    Ok((Command::Case(style), inputs))
+}
+
+fn parse_style(name: &str) -> Result<Case, String> {
+    let normalized = CaseFormatter::from(name).to_kebab_case();
+    match normalized.as_str() {
+        "camel" | "camel-case" => Ok(Case::Camel),
+        "pascal" | "pascal-case" => Ok(Case::Pascal),
+        "snake" | "snake-case" => Ok(Case::Snake),
+        "screaming-snake" | "screaming-snake-case" => Ok(Case::ScreamingSnake),
+        "kebab" | "kebab-case" => Ok(Case::Kebab),
+        "screaming-kebab" | "screaming-kebab-case" => Ok(Case::ScreamingKebab),
+        "dot" | "dot-case" => Ok(Case::Dot),
+        "title" | "title-case" => Ok(Case::Title),
+        "lower" | "lower-case" => Ok(Case::Lower),
+        "upper" | "upper-case" => Ok(Case::Upper),
+        _ => Err(format!("unknown style `{}`", name)),
+    }
+}
@@ -0,0 +1,107 @@
+use proptest::prelude::*;
+
+use crate::fmt_case_style::{ALL_CASES, Case, CaseFormatter};
+use crate::fmt_path::{
+    DriveCase, ExtensionCase, ExtensionSeparatorPolicy, PathFormatConfig, TrailingSlashPolicy,
+};
+
+/// Any [`Case`] variant
+pub fn case() -> impl Strategy<Value = Case> {
+    prop::sample::select(ALL_CASES.to_vec())
+}
+
+/// A single word as stored by [`CaseFormatter`]: 2 to 8 lowercase ASCII letters
+///
+/// Single letters are left out, as adjacent ones merge when a conversion is split again (`AB`).
+pub fn word() -> impl Strategy<Value = String> {
+    "[a-z]{2,8}"
+}
+
+/// A [`CaseFormatter`] built from up to 6 [`word`]s
+///
+/// # Examples
+///
+/// ```
+/// # use just_fmt::fmt_case_style::CaseFormatter;
+/// # use just_fmt::testing::{case, case_formatter};
+/// use proptest::prelude::*;
+///
+/// proptest!(|(processor in case_formatter(), case in case())| {
+///     let output = processor.convert(case);
+///     prop_assert_eq!(CaseFormatter::from(output.as_str()).convert(case), output);
+/// });
+/// ```
+pub fn case_formatter() -> impl Strategy<Value = CaseFormatter> {
+    prop::collection::vec(word(), 0..=6).prop_map(CaseFormatter::from_words)
+}
+
+/// A [`PathFormatConfig`] that passes [`PathFormatConfig::validate`] and never canonicalizes
+pub fn path_format_config() -> impl Strategy<Value = PathFormatConfig> {
+    let trailing_slash = prop::sample::select(vec![
+        TrailingSlashPolicy::Preserve,
+        TrailingSlashPolicy::AlwaysAdd,
+        TrailingSlashPolicy::AlwaysStrip,
+    ]);
+    let extension_case = prop::option::of(prop::sample::select(vec![
+        ExtensionCase::Lower,
+        ExtensionCase::Upper,
+    ]));
+    let normalize_drive_letter = prop::option::of(prop::sample::select(vec![
+        DriveCase::Upper,
+        DriveCase::Lower,
+        DriveCase::Preserve,
+    ]));
+    let extension_separator_policy = prop::sample::select(vec![
+        ExtensionSeparatorPolicy::LastDot,
+        ExtensionSeparatorPolicy::FirstDot,
+        ExtensionSeparatorPolicy::None,
+    ]);
+
+    (
+        prop::collection::vec(any::<bool>(), 20),
+        trailing_slash,
+        extension_case,
+        normalize_drive_letter,
+        extension_separator_policy,
+    )
+        .prop_map(
+            |(
+                flags,
+                trailing_slash,
+                extension_case,
+                normalize_drive_letter,
+                extension_separator_policy,
+            )| {
+                PathFormatConfig {
+                    strip_bom: flags[0],
+                    #[cfg(feature = "strip-ansi")]
+                    strip_ansi: flags[1],
+                    strip_file_scheme: flags[2],
+                    strip_verbatim_prefix: flags[15],
+                    strip_url_scheme: flags[16],
+                    strip_url_host: flags[17],
+                    strip_unfriendly_chars: flags[3],
+                    trim_component_trailing_dots_spaces: flags[19],
+                    resolve_parent_dirs: flags[4],
+                    collapse_cur_dir: flags[13],
+                    collapse_consecutive_slashes: flags[5],
+                    preserve_unc_prefix: flags[14],
+                    escape_backslashes: flags[6],
+                    extension_case,
+                    normalize_drive_letter,
+                    extension_separator_policy,
+                    trailing_slash,
+                    strip_leading_slash: flags[7],
+                    keep_leading_current_dir: flags[12],
+                    ensure_leading_slash: !flags[7] && !flags[12] && flags[8],
+                    #[cfg(feature = "fs")]
+                    canonicalize: false,
+                    lowercase: flags[9],
+                    lowercase_ascii_only: flags[10],
+                    reject_hidden: flags[11],
+                    sanitize_reserved_names: flags[18],
+                    reserved_name_suffix: "_",
+                }
+            },
+        )
+}
@@ -0,0 +1,51 @@
+//! Property tests built on the `testing` strategies and `Arbitrary` implementations.
+
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use just_fmt::fmt_case_style::{Case, CaseFormatter};
+use just_fmt::fmt_path::{PathFormatConfig, fmt_path_str_custom};
+use just_fmt::testing::{case, case_formatter, path_format_config};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn conversion_is_idempotent(processor in case_formatter(), case in case()) {
+        let output = processor.convert(case);
+        let reparsed = CaseFormatter::from(output.as_str());
+        prop_assert_eq!(reparsed.words(), processor.words());
+        prop_assert_eq!(reparsed.convert(case), output);
+    }
+
+    #[test]
+    fn generated_configs_are_valid(config in path_format_config()) {
+        prop_assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn formatting_with_generated_configs_is_idempotent(
+        config in path_format_config(),
+        path in "[a-zA-Z./]{0,24}",
+    ) {
+        let config = PathFormatConfig {
+            reject_hidden: false,
+            ..config
+        };
+        let formatted = fmt_path_str_custom(path.as_str(), &config).unwrap();
+        prop_assert_eq!(fmt_path_str_custom(formatted.as_str(), &config).unwrap(), formatted);
+    }
+
+    #[test]
+    fn arbitrary_values_respect_invariants(bytes in prop::collection::vec(any::<u8>(), 0..256)) {
+        let mut u = Unstructured::new(&bytes);
+        let processor = CaseFormatter::arbitrary(&mut u).unwrap();
+        let lowercase_word = |word: &String| !word.is_empty() && word.chars().all(|c| c.is_ascii_lowercase());
+        prop_assert!(processor.words().iter().all(lowercase_word));
+
+        let case = Case::arbitrary(&mut u).unwrap();
+        prop_assert_eq!(CaseFormatter::from(processor.convert(case).as_str()), processor);
+
+        let config = PathFormatConfig::arbitrary(&mut u).unwrap();
+        prop_assert!(config.validate().is_ok());
+    }
+}
@@ -0,0 +1,127 @@
+//! Property tests feeding arbitrary input through `CaseFormatter` and every converter,
+//! asserting that nothing panics and that output size stays proportional to the input.
+
+use just_fmt::fmt_case_style::{
+    Case, CaseFormatter, SlugOptions, SplitConfig, TitleCaseOptions, convert_embedded,
+    matches_case, slugify,
+};
+use proptest::prelude::*;
+
+const CASES: [Case; 10] = [
+    Case::Camel,
+    Case::Pascal,
+    Case::Snake,
+    Case::ScreamingSnake,
+    Case::Kebab,
+    Case::ScreamingKebab,
+    Case::Dot,
+    Case::Title,
+    Case::Lower,
+    Case::Upper,
+];
+
+/// Every converted word comes from the input and at most one separator is added per word
+fn bound(input: &str) -> usize {
+    2 * input.len() + 1
+}
+
+fn check_converters(processor: &CaseFormatter, input: &str) {
+    for case in CASES {
+        let output = processor.convert(case);
+        assert!(output.len() <= bound(input), "{:?} of {:?}", case, input);
+        let _ = matches_case(&output, case);
+    }
+
+    for output in [
+        processor.to_path_case(),
+        processor.to_kebab_case_upper(),
+        processor.to_pascal_case_with_numbers_attached(),
+        processor.to_title_case_custom(&TitleCaseOptions::default()),
+        processor.to_name_case(),
+        processor.to_slug(),
+    ] {
+        assert!(output.len() <= bound(input), "{:?}", input);
+    }
+
+    let _ = processor.to_env_var(Some("app"));
+    let _ = processor.to_filename(Some("txt"));
+    let _ = processor.format_template("{snake}{{}}{camel}");
+    assert!(processor.to_case_truncated_unique(Case::Snake, 16).len() <= 16);
+}
+
+proptest! {
+    #[test]
+    fn split_never_panics(input in any::<String>()) {
+        let processor = CaseFormatter::from(input.as_str());
+        check_converters(&processor, &input);
+
+        // Splitting is idempotent on its own snake case output
+        let resplit = CaseFormatter::from(processor.to_snake_case());
+        prop_assert_eq!(resplit.to_snake_case(), processor.to_snake_case());
+    }
+
+    #[test]
+    fn split_configs_never_panic(
+        input in "\\PC{0,64}",
+        preserve_underscores in any::<bool>(),
+        merge_single_letters in any::<bool>(),
+        split_after_digits in any::<bool>(),
+        person_name in any::<bool>(),
+    ) {
+        let base = if person_name { SplitConfig::person_name() } else { SplitConfig::default() };
+        let config = SplitConfig {
+            preserve_underscores,
+            merge_single_letters,
+            split_after_digits,
+            ..base
+        }
+        .strip_prefixes(&["m_", "I"])
+        .strip_suffixes(&["_t", "Impl"]);
+
+        let processor = CaseFormatter::from_custom(input.as_str(), &config);
+        for case in CASES {
+            let _ = processor.convert(case);
+        }
+        let _ = processor.to_name_case();
+    }
+
+    #[test]
+    fn from_words_never_panics(
+        words in proptest::collection::vec("\\PC{0,16}", 0..6),
+        max_len in 0usize..32,
+    ) {
+        // Unlike `from`, `from_words` keeps non-ASCII text, so cuts must respect code points
+        let processor = CaseFormatter::from_words(words);
+        for case in CASES {
+            prop_assert!(processor.to_case_truncated_unique(case, max_len).len() <= max_len);
+        }
+        prop_assert!(processor.to_filename(Some("txt")).len() <= 255);
+        prop_assert!(processor.to_filename(None).len() <= 255);
+    }
+
+    #[test]
+    fn byte_input_never_panics(bytes in proptest::collection::vec(any::<u8>(), 0..128)) {
+        let lossy = CaseFormatter::from_utf8_lossy(&bytes);
+        let _ = lossy.to_snake_case();
+
+        match CaseFormatter::from_utf8(&bytes) {
+            Ok(processor) => prop_assert_eq!(processor, lossy),
+            Err(_) => prop_assert!(std::str::from_utf8(&bytes).is_err()),
+        }
+
+        if let Ok(processor) = CaseFormatter::from_ascii_bytes(&bytes) {
+            prop_assert_eq!(processor, CaseFormatter::from_utf8(&bytes).unwrap());
+        }
+    }
+
+    #[test]
+    fn free_functions_never_panic(input in "\\PC{0,64}", max_length in proptest::option::of(0usize..32)) {
+        let slug = slugify(&input, &SlugOptions { transliterate: true, max_length });
+        if let Some(max_length) = max_length {
+            prop_assert!(slug.len() <= max_length);
+        }
+        for case in CASES {
+            let _ = convert_embedded(&input, case);
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Integration tests running the `just_fmt` binary.
+
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn run(args: &[&str], stdin: Option<&str>) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_just_fmt"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut child_stdin = child.stdin.take().unwrap();
+    child_stdin
+        .write_all(stdin.unwrap_or_default().as_bytes())
+        .unwrap();
+    drop(child_stdin);
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(args: &[&str], stdin: Option<&str>) -> String {
+    let output = run(args, stdin);
+    assert!(output.status.success(), "Failed for args: {:?}", args);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_case_styles() {
+    let test_cases = [
+        ("snake", "brew_coffee\n"),
+        ("camel", "brewCoffee\n"),
+        ("SCREAMING_SNAKE", "BREW_COFFEE\n"),
+        ("kebab-case", "brew-coffee\n"),
+        ("title", "Brew Coffee\n"),
+    ];
+    for (style, expected) in test_cases {
+        assert_eq!(
+            stdout(&["--style", style, "BrewCoffee"], None),
+            expected,
+            "Failed for style: '{}'",
+            style
+        );
+    }
+
+    assert_eq!(
+        stdout(&["--style", "pascal", "brew_coffee", "hot-milk"], None),
+        "BrewCoffee\nHotMilk\n"
+    );
+}
+
+#[test]
+fn test_stdin_lines() {
+    assert_eq!(
+        stdout(&["--style", "snake"], Some("BrewCoffee\nhotMilk\n")),
+        "brew_coffee\nhot_milk\n"
+    );
+    assert_eq!(stdout(&["path"], Some("a//b/../c\n./d/\n")), "a/c\nd/\n");
+}
+
+#[test]
+fn test_path() {
+    assert_eq!(
+        stdout(&["path", "--strip-trailing", "C:\\a\\\\b\\"], None),
+        "C:/a/b\n"
+    );
+    assert_eq!(
+        stdout(
+            &["path", "--lowercase", "--add-trailing", "/Home/User"],
+            None
+        ),
+        "/home/user/\n"
+    );
+}
+
+#[test]
+fn test_usage_errors() {
+    for args in [
+        &["BrewCoffee"][..],
+        &["--style", "nope", "BrewCoffee"],
+        &["--style"],
+        &["path", "--strip-leading", "--ensure-leading", "/a"],
+        &["path", "--unknown", "/a"],
+    ] {
+        let output = run(args, None);
+        assert_eq!(output.status.code(), Some(2), "Failed for args: {:?}", args);
+        assert!(
+            String::from_utf8(output.stderr)
+                .unwrap()
+                .starts_with("error: ")
+        );
+    }
+
+    let help = run(&["--help"], None);
+    assert!(help.status.success());
+    assert!(
+        String::from_utf8(help.stdout)
+            .unwrap()
+            .starts_with("Usage:")
+    );
+}
@@ -0,0 +1,94 @@
+//! Property tests feeding arbitrary and path-like input through `fmt_path_str`,
+//! checking the invariants documented on the `fmt_path` module.
+
+use just_fmt::fmt_path::{
+    PathFormatConfig, TrailingSlashPolicy, fmt_path_str, fmt_path_str_custom,
+};
+use proptest::prelude::*;
+
+/// Path-like strings: separators, dot segments, control characters and unfriendly characters
+fn path_like() -> impl Strategy<Value = String> {
+    let segment = prop_oneof![
+        Just(String::new()),
+        Just(".".to_string()),
+        Just("..".to_string()),
+        "[a-zA-Z0-9 _.-]{1,6}",
+        "[\\x00-\\x1f*?\"<>|:]{1,3}",
+        Just("C:".to_string()),
+    ];
+    let separator = prop_oneof![Just("/"), Just("\\"), Just("//")];
+    (
+        any::<bool>(),
+        prop::collection::vec((segment, separator), 0..24),
+    )
+        .prop_map(|(bom, parts)| {
+            let body: String = parts
+                .into_iter()
+                .map(|(segment, separator)| format!("{}{}", segment, separator))
+                .collect();
+            format!("{}{}", if bom { "\u{FEFF}" } else { "" }, body)
+        })
+}
+
+/// Long chains of `..` mixed with a few names, rooted or not
+fn parent_chain() -> impl Strategy<Value = String> {
+    (
+        any::<bool>(),
+        prop::collection::vec(
+            prop_oneof![3 => Just("..".to_string()), 1 => "[a-z]{1,3}"],
+            0..64,
+        ),
+    )
+        .prop_map(|(rooted, segments)| {
+            format!("{}{}", if rooted { "/" } else { "" }, segments.join("/"))
+        })
+}
+
+fn check_invariants(input: &str) -> Result<(), TestCaseError> {
+    let formatted = fmt_path_str(input).unwrap();
+    // Windows verbatim and device paths are returned untouched
+    if formatted.starts_with(r"\\?\") || formatted.starts_with(r"\\.\") {
+        prop_assert_eq!(fmt_path_str(formatted.as_str()).unwrap(), formatted);
+        return Ok(());
+    }
+    // Only a UNC prefix (`//server`) keeps its double slash
+    let body = formatted.get(1..).unwrap_or_default();
+    prop_assert!(!body.contains("//"), "{:?} -> {:?}", input, formatted);
+
+    if input
+        .trim_start_matches('\u{FEFF}')
+        .starts_with(['/', '\\'])
+    {
+        prop_assert!(formatted.starts_with('/'), "{:?} -> {:?}", input, formatted);
+    }
+
+    if !input.contains('\x1b') && !input.trim_start_matches('\u{FEFF}').contains('\u{FEFF}') {
+        prop_assert_eq!(fmt_path_str(formatted.as_str()).unwrap(), formatted);
+    }
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn arbitrary_input_never_panics(input in any::<String>()) {
+        let _ = fmt_path_str(input.as_str());
+
+        let config = PathFormatConfig {
+            trailing_slash: TrailingSlashPolicy::AlwaysAdd,
+            ensure_leading_slash: true,
+            lowercase: true,
+            ..Default::default()
+        };
+        let _ = fmt_path_str_custom(input.as_str(), &config);
+    }
+
+    #[test]
+    fn path_like_input_invariants(input in path_like()) {
+        check_invariants(&input)?;
+    }
+
+    #[test]
+    fn parent_chain_invariants(input in parent_chain()) {
+        check_invariants(&input)?;
+    }
+}